@@ -0,0 +1,322 @@
+use crate::*;
+use crate::point::*;
+use crate::line::*;
+use crate::round::*;
+use crate::convex_hull::*;
+use std::fmt;
+
+/// `Aabb` 轴对齐矩形，由左下角 `lo` 与右上角 `hi` 表示，常用作其他图形的包围盒。
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Aabb {
+    pub lo: Point,
+    pub hi: Point,
+}
+
+impl fmt::Display for Aabb {
+    /// 支持以左下角与右上角坐标形式输出矩形。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::aabb::Aabb;
+    ///
+    ///     let b = Aabb::new(Point::new(0.0, 0.0), Point::new(1.0, 2.0));
+    ///     println!("{}", b); // <(0.00000,0.00000) (1.00000,2.00000)>
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{} {}>", self.lo, self.hi)
+    }
+}
+
+impl Aabb {
+    /// 通过任意两个对角顶点构造矩形，会自动整理为左下角与右上角。
+    pub fn new(a: Point, b: Point) -> Self {
+        Aabb {
+            lo: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            hi: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    /// 计算点集的包围盒，点集为空时返回 `None`。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::aabb::Aabb;
+    ///
+    ///     let pts = vec![Point::new(1.0, 3.0), Point::new(-1.0, 0.0), Point::new(2.0, 1.0)];
+    ///     let b = Aabb::from_points(&pts).unwrap();
+    ///     assert_eq!(b, Aabb::new(Point::new(-1.0, 0.0), Point::new(2.0, 3.0)));
+    ///
+    pub fn from_points(pts: &[Point]) -> Option<Self> {
+        let first = *pts.first()?;
+        Some(pts[1 ..].iter().fold(Aabb { lo: first, hi: first }, |b, &p| b.expand(p)))
+    }
+
+    /// 计算线段的包围盒。
+    pub fn from_line(l: Line) -> Self {
+        Aabb::new(l.a, l.b)
+    }
+
+    /// 计算圆的包围盒。
+    pub fn from_round(rd: Round) -> Self {
+        let d = Point::new(rd.r, rd.r);
+        Aabb { lo: rd.o - d, hi: rd.o + d }
+    }
+
+    /// 计算凸包的包围盒，凸包为空时返回 `None`。由于上下凸壳按横坐标排序，横坐标范围可直接由端点得到。
+    pub fn from_convex_hull(hull: &ConvexHull) -> Option<Self> {
+        let (first, last) = (*hull.d_hull.first()?, *hull.d_hull.last()?);
+        let pts = hull.u_hull.iter().chain(hull.d_hull.iter());
+        let (lo_y, hi_y) = pts.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), p| (lo.min(p.y), hi.max(p.y)));
+        Some(Aabb {
+            lo: Point::new(first.x, lo_y),
+            hi: Point::new(last.x, hi_y),
+        })
+    }
+
+    /// 返回扩展到包含点 `p` 之后的矩形。
+    pub fn expand(&self, p: Point) -> Self {
+        Aabb {
+            lo: Point::new(self.lo.x.min(p.x), self.lo.y.min(p.y)),
+            hi: Point::new(self.hi.x.max(p.x), self.hi.y.max(p.y)),
+        }
+    }
+
+    /// 返回同时包含两个矩形的最小矩形。
+    pub fn merge(&self, b: Aabb) -> Self {
+        self.expand(b.lo).expand(b.hi)
+    }
+
+    /// 矩形宽度。
+    pub fn width(&self) -> f64 {
+        self.hi.x - self.lo.x
+    }
+
+    /// 矩形高度。
+    pub fn height(&self) -> f64 {
+        self.hi.y - self.lo.y
+    }
+
+    /// 矩形面积。
+    pub fn area(&self) -> f64 {
+        self.width() * self.height()
+    }
+
+    /// 矩形周长。
+    pub fn perimeter(&self) -> f64 {
+        (self.width() + self.height()) * 2.0
+    }
+
+    /// 矩形中心。
+    pub fn center(&self) -> Point {
+        (self.lo + self.hi) / 2.0
+    }
+
+    /// 判断点是否在矩形内（含边界，基于 `EPS`）。
+    pub fn contains(&self, p: Point) -> bool {
+        p.x > self.lo.x - EPS && p.x < self.hi.x + EPS && p.y > self.lo.y - EPS && p.y < self.hi.y + EPS
+    }
+
+    /// 判断矩形 `b` 是否完全在该矩形内（含边界）。
+    pub fn contains_aabb(&self, b: Aabb) -> bool {
+        self.contains(b.lo) && self.contains(b.hi)
+    }
+
+    /// 判断两个矩形是否有公共点（边界接触也算作重叠）。
+    pub fn overlaps(&self, b: Aabb) -> bool {
+        self.intersection(b).is_some()
+    }
+
+    /// 计算两个矩形的交，没有公共点时返回 `None`。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::aabb::Aabb;
+    ///
+    ///     let a = Aabb::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+    ///     let b = Aabb::new(Point::new(1.0, 1.0), Point::new(3.0, 3.0));
+    ///     assert_eq!(a.intersection(b), Some(Aabb::new(Point::new(1.0, 1.0), Point::new(2.0, 2.0))));
+    ///
+    pub fn intersection(&self, b: Aabb) -> Option<Self> {
+        let lo = Point::new(self.lo.x.max(b.lo.x), self.lo.y.max(b.lo.y));
+        let hi = Point::new(self.hi.x.min(b.hi.x), self.hi.y.min(b.hi.y));
+        if lo.x > hi.x + EPS || lo.y > hi.y + EPS {
+            None
+        }
+        else {
+            Some(Aabb { lo, hi: Point::new(hi.x.max(lo.x), hi.y.max(lo.y)) })
+        }
+    }
+}
+
+/// 矩形并扫描线中使用的线段树，维护纵坐标区间的覆盖长度与覆盖段数。
+struct CoverTree {
+    ys: Vec<f64>,
+    cnt: Vec<i32>,
+    len: Vec<f64>,
+    segs: Vec<usize>,
+    lcov: Vec<bool>,
+    rcov: Vec<bool>,
+}
+
+impl CoverTree {
+    fn new(ys: Vec<f64>) -> Self {
+        let n = 4 * ys.len().max(1);
+        CoverTree {
+            ys,
+            cnt: vec![0; n],
+            len: vec![0.0; n],
+            segs: vec![0; n],
+            lcov: vec![false; n],
+            rcov: vec![false; n],
+        }
+    }
+
+    /// 对基本区间 `[l, r)`（对应 `ys[l] .. ys[r]`）中与 `[ql, qr)` 重叠的部分加上覆盖次数 `v`。
+    fn update(&mut self, u: usize, l: usize, r: usize, ql: usize, qr: usize, v: i32) {
+        if qr <= l || r <= ql {
+            return
+        }
+        if ql <= l && r <= qr {
+            self.cnt[u] += v;
+        }
+        else {
+            let m = (l + r) / 2;
+            self.update(u * 2, l, m, ql, qr, v);
+            self.update(u * 2 + 1, m, r, ql, qr, v);
+        }
+        self.pull(u, l, r);
+    }
+
+    fn pull(&mut self, u: usize, l: usize, r: usize) {
+        if self.cnt[u] > 0 {
+            self.len[u] = self.ys[r] - self.ys[l];
+            self.segs[u] = 1;
+            self.lcov[u] = true;
+            self.rcov[u] = true;
+        }
+        else if r - l == 1 {
+            self.len[u] = 0.0;
+            self.segs[u] = 0;
+            self.lcov[u] = false;
+            self.rcov[u] = false;
+        }
+        else {
+            let (a, b) = (u * 2, u * 2 + 1);
+            self.len[u] = self.len[a] + self.len[b];
+            self.segs[u] = self.segs[a] + self.segs[b] - (self.rcov[a] && self.lcov[b]) as usize;
+            self.lcov[u] = self.lcov[a];
+            self.rcov[u] = self.rcov[b];
+        }
+    }
+}
+
+/// 扫描矩形的并，返回并的面积与周长。
+fn union_sweep(rects: &[Aabb]) -> (f64, f64) {
+    let rects: Vec<&Aabb> = rects.iter().filter(|b| b.width() > EPS && b.height() > EPS).collect();
+    if rects.is_empty() {
+        return (0.0, 0.0)
+    }
+
+    let mut ys: Vec<f64> = rects.iter().flat_map(|b| [b.lo.y, b.hi.y]).collect();
+    ys.sort_by(f64::total_cmp);
+    ys.dedup_by(|a, b| eq_f64(*a, *b));
+    let find = |y: f64| ys.partition_point(|&v| v < y - EPS);
+
+    let mut events: Vec<(f64, i32, usize, usize)> = Vec::new();
+    for b in rects {
+        let (l, r) = (find(b.lo.y), find(b.hi.y));
+        events.push((b.lo.x, 1, l, r));
+        events.push((b.hi.x, -1, l, r));
+    }
+    events.sort_by(|a, b| a.0.total_cmp(&b.0).then(b.1.cmp(&a.1)));
+
+    let m = ys.len() - 1;
+    let mut tree = CoverTree::new(ys);
+    let (mut area, mut perimeter) = (0.0, 0.0);
+    let mut last_x = events[0].0;
+    for (x, v, l, r) in events {
+        let dx = x - last_x;
+        area += tree.len[1] * dx;
+        perimeter += 2.0 * tree.segs[1] as f64 * dx;
+        let before = tree.len[1];
+        tree.update(1, 0, m, l, r, v);
+        perimeter += (tree.len[1] - before).abs();
+        last_x = x;
+    }
+    (area, perimeter)
+}
+
+/// 使用扫描线加线段树计算若干矩形并的面积，时间复杂度 O(n log n)。
+///
+///     use rust_geometry::eq_f64;
+///     use rust_geometry::point::Point;
+///     use rust_geometry::aabb::{Aabb, union_area};
+///
+///     let rects = vec![Aabb::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0)),
+///                      Aabb::new(Point::new(1.0, 1.0), Point::new(3.0, 3.0))];
+///     assert!(eq_f64(union_area(&rects), 7.0));
+///
+pub fn union_area(rects: &[Aabb]) -> f64 {
+    union_sweep(rects).0
+}
+
+/// 使用扫描线加线段树计算若干矩形并的周长（包括内部空洞的边界），时间复杂度 O(n log n)。
+///
+///     use rust_geometry::eq_f64;
+///     use rust_geometry::point::Point;
+///     use rust_geometry::aabb::{Aabb, union_perimeter};
+///
+///     let rects = vec![Aabb::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0)),
+///                      Aabb::new(Point::new(1.0, 1.0), Point::new(3.0, 3.0))];
+///     assert!(eq_f64(union_perimeter(&rects), 12.0));
+///
+pub fn union_perimeter(rects: &[Aabb]) -> f64 {
+    union_sweep(rects).1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x1: f64, y1: f64, x2: f64, y2: f64) -> Aabb {
+        Aabb::new(Point::new(x1, y1), Point::new(x2, y2))
+    }
+
+    #[test]
+    fn bounding_box_test() {
+        let l = Line::new(Point::new(3.0, -1.0), Point::new(1.0, 2.0));
+        assert_eq!(Aabb::from_line(l), rect(1.0, -1.0, 3.0, 2.0));
+
+        let rd = Round::new(Point::new(1.0, 1.0), 2.0);
+        assert_eq!(Aabb::from_round(rd), rect(-1.0, -1.0, 3.0, 3.0));
+
+        let hull = ConvexHull::get_convex_hull(vec![Point::new(0.0, 0.0), Point::new(2.0, -1.0),
+                                                    Point::new(4.0, 0.0), Point::new(1.0, 3.0)]);
+        assert_eq!(Aabb::from_convex_hull(&hull), Some(rect(0.0, -1.0, 4.0, 3.0)));
+        assert_eq!(Aabb::from_convex_hull(&ConvexHull::get_convex_hull(vec![])), None);
+        let single = ConvexHull::get_convex_hull(vec![Point::new(1.0, 2.0)]);
+        assert_eq!(Aabb::from_convex_hull(&single), Some(rect(1.0, 2.0, 1.0, 2.0)));
+
+        let b = rect(0.0, 0.0, 2.0, 2.0);
+        assert!(b.contains(Point::new(2.0, 1.0)));
+        assert!(!b.contains(Point::new(2.1, 1.0)));
+        assert!(b.contains_aabb(rect(0.5, 0.5, 1.0, 2.0)));
+        assert!(b.overlaps(rect(2.0, 2.0, 3.0, 3.0)));
+        assert!(!b.overlaps(rect(2.5, 0.0, 3.0, 3.0)));
+    }
+
+    #[test]
+    fn union_test() {
+        // 回字形：外框减去中间的洞，洞的边界计入周长。
+        let rects = vec![rect(0.0, 0.0, 3.0, 1.0), rect(0.0, 2.0, 3.0, 3.0),
+                         rect(0.0, 0.0, 1.0, 3.0), rect(2.0, 0.0, 3.0, 3.0)];
+        assert!(eq_f64(union_area(&rects), 8.0));
+        assert!(eq_f64(union_perimeter(&rects), 16.0));
+
+        // 相邻接触的矩形拼成一个大矩形，重复矩形不影响结果。
+        let rects = vec![rect(0.0, 0.0, 1.0, 1.0), rect(1.0, 0.0, 2.0, 1.0),
+                         rect(0.0, 1.0, 2.0, 2.0), rect(0.0, 1.0, 2.0, 2.0)];
+        assert!(eq_f64(union_area(&rects), 4.0));
+        assert!(eq_f64(union_perimeter(&rects), 8.0));
+
+        assert!(eq_f64(union_area(&[]), 0.0));
+    }
+}
@@ -0,0 +1,309 @@
+use crate::*;
+use crate::point::*;
+use crate::line::*;
+use crate::round::*;
+
+/// 构造圆时的约束条件：经过一个点、与一条直线相切或与一个圆相切。
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Constraint {
+    Through(Point),
+    TangentLine(Line),
+    TangentRound(Round),
+}
+
+/// 三维向量的点乘与叉乘，用于求解关于 `(x, y, r)` 的线性方程组。
+fn dot3(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross3(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+/// 将直线向左侧平移 `d` 的距离。
+fn offset(l: Line, d: f64) -> Line {
+    let v = l.vec().normalize();
+    let n = Point::new(-v.y, v.x) * d;
+    Line::new(l.a + n, l.b + n)
+}
+
+/// 判断圆是否满足约束。
+fn satisfies(rd: Round, c: Constraint) -> bool {
+    match c {
+        Constraint::Through(p) => eq_f64((p - rd.o).dis(), rd.r),
+        Constraint::TangentLine(l) => eq_f64((l.proj(rd.o) - rd.o).dis(), rd.r),
+        Constraint::TangentRound(c) => {
+            let d = (c.o - rd.o).dis();
+            eq_f64(d, rd.r + c.r) || eq_f64(d, (rd.r - c.r).abs())
+        }
+    }
+}
+
+/// 把约束按给定的相切方向写成关于 `(x, y, r)` 的方程。
+///
+/// 圆 `(xi, yi, ri)` 的方程为 `x^2 + y^2 - r^2 + L(x, y, r) = 0`，返回 `(true, L 的系数, L 的常数项)`；
+/// 直线的方程为线性方程 `n · (x, y, r) = d`，返回 `(false, n, d)`。
+fn equation(c: Constraint, sign: f64) -> (bool, [f64; 3], f64) {
+    match c {
+        Constraint::Through(p) => (true, [-2.0 * p.x, -2.0 * p.y, 0.0], p.sqrdis()),
+        Constraint::TangentRound(c) => {
+            (true, [-2.0 * c.o.x, -2.0 * c.o.y, -2.0 * sign * c.r], c.o.sqrdis() - c.r * c.r)
+        }
+        Constraint::TangentLine(l) => {
+            let v = l.vec().normalize();
+            let n = Point::new(-v.y, v.x);
+            (false, [n.x, n.y, -sign], n * l.a)
+        }
+    }
+}
+
+/// 求解满足一组相切方向的圆，返回所有 `(x, y, r)` 的解。
+fn solve(cs: &[Constraint; 3], signs: [f64; 3]) -> Vec<[f64; 3]> {
+    let eqs: Vec<(bool, [f64; 3], f64)> = (0 .. 3).map(|i| equation(cs[i], signs[i])).collect();
+
+    // 用第一个圆的方程消去其余圆方程中的二次项，得到两个线性方程。
+    let quad = eqs.iter().position(|e| e.0);
+    let mut lin: Vec<([f64; 3], f64)> = Vec::new();
+    for (i, e) in eqs.iter().enumerate() {
+        match quad {
+            Some(q) if i == q => {}
+            Some(q) if e.0 => {
+                let f = &eqs[q];
+                lin.push(([e.1[0] - f.1[0], e.1[1] - f.1[1], e.1[2] - f.1[2]], f.2 - e.2));
+            }
+            _ => lin.push((e.1, e.2)),
+        }
+    }
+
+    let q = match quad {
+        Some(q) => eqs[q],
+        None => {
+            // 三条直线：直接解三元一次方程组。
+            let (n1, n2, n3) = (lin[0].0, lin[1].0, lin[2].0);
+            let det = dot3(n1, cross3(n2, n3));
+            if eq_f64(det, 0.0) {
+                return Vec::new()
+            }
+            let (c23, c31, c12) = (cross3(n2, n3), cross3(n3, n1), cross3(n1, n2));
+            return vec![[0, 1, 2].map(|k| (lin[0].1 * c23[k] + lin[1].1 * c31[k] + lin[2].1 * c12[k]) / det)]
+        }
+    };
+
+    // 两个线性方程的解为一条直线 P0 + tD，代入二次方程解出 t。
+    let ((n1, d1), (n2, d2)) = (lin[0], lin[1]);
+    let d = cross3(n1, n2);
+    let dd = dot3(d, d);
+    if eq_f64(dd, 0.0) {
+        return Vec::new()
+    }
+    let (a1, a2, a12) = (dot3(n1, n1), dot3(n2, n2), dot3(n1, n2));
+    let p0 = [0, 1, 2].map(|k| ((d1 * a2 - d2 * a12) * n1[k] + (d2 * a1 - d1 * a12) * n2[k]) / dd);
+
+    let form = |u: [f64; 3], v: [f64; 3]| u[0] * v[0] + u[1] * v[1] - u[2] * v[2];
+    let a = form(d, d);
+    let b = 2.0 * form(p0, d) + dot3(q.1, d);
+    let c = form(p0, p0) + dot3(q.1, p0) + q.2;
+    let ts = if eq_f64(a, 0.0) {
+        if eq_f64(b, 0.0) { Vec::new() } else { vec![-c / b] }
+    }
+    else {
+        let delta = b * b - 4.0 * a * c;
+        if delta < -EPS {
+            Vec::new()
+        }
+        else {
+            let s = delta.max(0.0).sqrt();
+            vec![(-b + s) / (2.0 * a), (-b - s) / (2.0 * a)]
+        }
+    };
+    ts.into_iter().map(|t| [0, 1, 2].map(|k| p0[k] + d[k] * t)).collect()
+}
+
+/// 把候选圆去重后加入结果。
+fn push_unique(res: &mut Vec<Round>, rd: Round) {
+    if !res.iter().any(|c| c.o == rd.o && eq_f64(c.r, rd.r)) {
+        res.push(rd);
+    }
+}
+
+/// 阿波罗尼奥斯问题：求出同时满足三个约束（经过点、与直线相切、与圆相切）的所有圆。
+///
+/// 对每个圆和直线枚举相切的方向（外切或内切、直线的哪一侧），每种方向组合化为一个二次方程求解，
+/// 因此三个圆两两相离时最多得到 8 个解。
+///
+///     use rust_geometry::point::Point;
+///     use rust_geometry::round::Round;
+///     use rust_geometry::apollonius::{apollonius, Constraint};
+///
+///     let cs = [Constraint::TangentRound(Round::new(Point::new(0.0, 0.0), 1.0)),
+///               Constraint::TangentRound(Round::new(Point::new(6.0, 0.0), 1.0)),
+///               Constraint::TangentRound(Round::new(Point::new(3.0, 5.0), 1.0))];
+///     assert_eq!(apollonius(cs).len(), 8);
+///
+///     let cs = [Constraint::Through(Point::new(0.0, 0.0)), Constraint::Through(Point::new(2.0, 0.0)),
+///               Constraint::Through(Point::new(0.0, 2.0))];
+///     let v = apollonius(cs);
+///     assert_eq!(v.len(), 1);
+///     assert_eq!(v[0].o, Point::new(1.0, 1.0));
+///
+pub fn apollonius(cs: [Constraint; 3]) -> Vec<Round> {
+    let choices = |c: &Constraint| match c {
+        Constraint::Through(_) => vec![1.0],
+        _ => vec![1.0, -1.0],
+    };
+
+    let mut res = Vec::new();
+    for &s0 in &choices(&cs[0]) {
+        for &s1 in &choices(&cs[1]) {
+            for &s2 in &choices(&cs[2]) {
+                for [x, y, r] in solve(&cs, [s0, s1, s2]) {
+                    let rd = Round::new(Point::new(x, y), r.abs());
+                    if rd.r > EPS && cs.iter().all(|&c| satisfies(rd, c)) {
+                        push_unique(&mut res, rd);
+                    }
+                }
+            }
+        }
+    }
+    res
+}
+
+/// 求出半径为 `r` 且与两条直线都相切的所有圆，两直线相交时有 4 个解。
+///
+///     use rust_geometry::point::Point;
+///     use rust_geometry::line::Line;
+///     use rust_geometry::apollonius::rounds_tangent_to_lines;
+///
+///     let la = Line::new(Point::new(0.0, 0.0), Point::new(1.0, 0.0));
+///     let lb = Line::new(Point::new(0.0, 0.0), Point::new(0.0, 1.0));
+///     let v = rounds_tangent_to_lines(la, lb, 1.0);
+///     assert_eq!(v.len(), 4);
+///     assert!(v.iter().any(|c| c.o == Point::new(1.0, 1.0)));
+///
+pub fn rounds_tangent_to_lines(la: Line, lb: Line, r: f64) -> Vec<Round> {
+    let mut res = Vec::new();
+    for da in [r, -r] {
+        for db in [r, -r] {
+            if let Some(o) = offset(la, da).inter(offset(lb, db)) {
+                push_unique(&mut res, Round::new(o, r));
+            }
+        }
+    }
+    res
+}
+
+/// 求出半径为 `r`、经过点 `p` 且与直线 `l` 相切的所有圆。
+///
+///     use rust_geometry::point::Point;
+///     use rust_geometry::line::Line;
+///     use rust_geometry::apollonius::rounds_through_point_tangent_to_line;
+///
+///     let l = Line::new(Point::new(0.0, 0.0), Point::new(1.0, 0.0));
+///     let v = rounds_through_point_tangent_to_line(Point::new(0.0, 1.0), l, 1.0);
+///     assert_eq!(v.len(), 2);
+///
+pub fn rounds_through_point_tangent_to_line(p: Point, l: Line, r: f64) -> Vec<Round> {
+    let mut res = Vec::new();
+    for d in [r, -r] {
+        for o in Round::new(p, r).inter_line(offset(l, d)).points() {
+            push_unique(&mut res, Round::new(o, r));
+        }
+    }
+    res
+}
+
+/// 求出半径为 `r` 且与两个圆都相切（外切或内切）的所有圆。
+///
+///     use rust_geometry::point::Point;
+///     use rust_geometry::round::Round;
+///     use rust_geometry::apollonius::rounds_tangent_to_rounds;
+///
+///     let a = Round::new(Point::new(0.0, 0.0), 1.0);
+///     let b = Round::new(Point::new(4.0, 0.0), 1.0);
+///     let v = rounds_tangent_to_rounds(a, b, 1.0);
+///     assert_eq!(v.len(), 1);
+///     assert_eq!(v[0].o, Point::new(2.0, 0.0));
+///
+pub fn rounds_tangent_to_rounds(a: Round, b: Round, r: f64) -> Vec<Round> {
+    let mut res = Vec::new();
+    for ra in [a.r + r, (a.r - r).abs()] {
+        for rb in [b.r + r, (b.r - r).abs()] {
+            if eq_f64(ra, 0.0) || eq_f64(rb, 0.0) {
+                continue
+            }
+            for o in Round::new(a.o, ra).inter_round(Round::new(b.o, rb)).points() {
+                push_unique(&mut res, Round::new(o, r));
+            }
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn apollonius_test() {
+        let a = Round::new(Point::new(0.0, 0.0), 1.0);
+        let b = Round::new(Point::new(7.0, 1.0), 2.0);
+        let c = Round::new(Point::new(2.0, 6.0), 1.5);
+        let cs = [Constraint::TangentRound(a), Constraint::TangentRound(b), Constraint::TangentRound(c)];
+        let v = apollonius(cs);
+        assert_eq!(v.len(), 8);
+        for rd in v {
+            assert!(cs.iter().all(|&c| satisfies(rd, c)));
+        }
+
+        // 两条平行线与一个夹在中间的圆：与两直线都相切的圆半径为 1，圆心在 y = 1 上。
+        let la = Line::new(Point::new(0.0, 0.0), Point::new(1.0, 0.0));
+        let lb = Line::new(Point::new(0.0, 2.0), Point::new(1.0, 2.0));
+        let cs = [Constraint::TangentLine(la), Constraint::TangentLine(lb),
+                  Constraint::TangentRound(Round::new(Point::new(0.0, 1.0), 0.5))];
+        let v = apollonius(cs);
+        assert_eq!(v.len(), 4);
+        for rd in v {
+            assert!(eq_f64(rd.r, 1.0) && eq_f64(rd.o.y, 1.0));
+        }
+
+        // 三条直线围成的三角形：内切圆与三个旁切圆。
+        let cs = [Constraint::TangentLine(la),
+                  Constraint::TangentLine(Line::new(Point::new(0.0, 0.0), Point::new(0.0, 1.0))),
+                  Constraint::TangentLine(Line::new(Point::new(3.0, 0.0), Point::new(0.0, 4.0)))];
+        let v = apollonius(cs);
+        assert_eq!(v.len(), 4);
+        assert!(v.iter().any(|c| c.o == Point::new(1.0, 1.0) && eq_f64(c.r, 1.0)));
+
+        // 两点一直线。
+        let cs = [Constraint::Through(Point::new(-1.0, 2.0)), Constraint::Through(Point::new(1.0, 2.0)),
+                  Constraint::TangentLine(la)];
+        let v = apollonius(cs);
+        assert_eq!(v.len(), 1);
+        assert_eq!(v[0].o, Point::new(0.0, 1.25));
+    }
+
+    #[test]
+    fn fixed_radius_test() {
+        let la = Line::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0));
+        let lb = Line::new(Point::new(0.0, 3.0), Point::new(1.0, 3.0));
+        for rd in rounds_tangent_to_lines(la, lb, 0.5) {
+            assert!(satisfies(rd, Constraint::TangentLine(la)) && satisfies(rd, Constraint::TangentLine(lb)));
+        }
+
+        let p = Point::new(1.0, 2.0);
+        let v = rounds_through_point_tangent_to_line(p, la, 2.0);
+        assert_eq!(v.len(), 2);
+        for rd in v {
+            assert!(satisfies(rd, Constraint::Through(p)) && satisfies(rd, Constraint::TangentLine(la)));
+        }
+
+        let a = Round::new(Point::new(0.0, 0.0), 2.0);
+        let b = Round::new(Point::new(3.0, 0.0), 2.0);
+        let v = rounds_tangent_to_rounds(a, b, 1.0);
+        assert_eq!(v.len(), 6);
+        for rd in v {
+            assert!(satisfies(rd, Constraint::TangentRound(a)) && satisfies(rd, Constraint::TangentRound(b)));
+        }
+    }
+}
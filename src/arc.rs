@@ -0,0 +1,218 @@
+use crate::*;
+use crate::point::*;
+use crate::line::*;
+use crate::round::*;
+use std::f64::consts::PI;
+use std::fmt;
+
+/// `Arc` 圆弧，由所在的圆 `rd` 以及从 `start` 逆时针转到 `end` 的角度范围表示，角度为弧度制。
+///
+/// 若 `end - start` 位于 [0, 2pi] 内，则它就是圆弧的圆心角，否则取其对 2pi 的余数，因此 `Arc::new(rd, 0.0, 2.0 * PI)` 表示整个圆。
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Arc {
+    pub rd: Round,
+    pub start: f64,
+    pub end: f64,
+}
+
+impl fmt::Display for Arc {
+    /// 支持以圆及起止角的形式输出圆弧。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///     use rust_geometry::arc::Arc;
+    ///
+    ///     let a = Arc::new(Round::new(Point::new(0.0, 0.0), 1.0), 0.0, 1.0);
+    ///     println!("{}", a); // [(0.00000,0.00000) 1]<0.00000,1.00000>
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}<{:.5},{:.5}>", self.rd, self.start, self.end)
+    }
+}
+
+impl Arc {
+    /// 通过圆及起止角构造圆弧。
+    pub fn new(rd: Round, start: f64, end: f64) -> Self {
+        Arc { rd, start, end }
+    }
+
+    /// 通过圆及圆上的起点、终点构造逆时针圆弧。
+    pub fn from_points(rd: Round, a: Point, b: Point) -> Self {
+        Arc { rd, start: (a - rd.o).polar_angle(), end: (b - rd.o).polar_angle() }
+    }
+
+    /// 圆弧的圆心角，在 [0, 2pi] 之间。
+    pub fn span(&self) -> f64 {
+        let d = self.end - self.start;
+        if (0.0 ..= 2.0 * PI).contains(&d) { d } else { d.rem_euclid(2.0 * PI) }
+    }
+
+    /// 圆上极角为 `theta` 的点。
+    pub fn point_at(&self, theta: f64) -> Point {
+        self.rd.o + Point::new(self.rd.r, 0.0).rot(theta)
+    }
+
+    /// 圆弧的起点。
+    pub fn start_point(&self) -> Point {
+        self.point_at(self.start)
+    }
+
+    /// 圆弧的终点。
+    pub fn end_point(&self) -> Point {
+        self.point_at(self.end)
+    }
+
+    /// 圆弧的长度。
+    pub fn len(&self) -> f64 {
+        self.rd.r * self.span()
+    }
+
+    /// 圆弧对应扇形的面积。
+    pub fn sector_area(&self) -> f64 {
+        self.rd.r * self.rd.r * self.span() / 2.0
+    }
+
+    /// 圆弧与其弦围成的弓形的面积，圆心角超过 pi 时同样适用。
+    ///
+    ///     use rust_geometry::eq_f64;
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///     use rust_geometry::arc::Arc;
+    ///
+    ///     let a = Arc::new(Round::new(Point::new(0.0, 0.0), 1.0), 0.0, std::f64::consts::PI / 2.0);
+    ///     assert!(eq_f64(a.segment_area(), std::f64::consts::PI / 4.0 - 0.5));
+    ///
+    pub fn segment_area(&self) -> f64 {
+        let theta = self.span();
+        self.rd.r * self.rd.r * (theta - theta.sin()) / 2.0
+    }
+
+    /// 判断极角 `theta` 是否在圆弧的角度范围内（含端点，基于 `EPS`）。
+    pub fn contains_angle(&self, theta: f64) -> bool {
+        let d = (theta - self.start).rem_euclid(2.0 * PI);
+        d < self.span() + EPS || d > 2.0 * PI - EPS
+    }
+
+    /// 判断点是否在圆弧上。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///     use rust_geometry::arc::Arc;
+    ///
+    ///     let a = Arc::new(Round::new(Point::new(0.0, 0.0), 1.0), 0.0, std::f64::consts::PI);
+    ///     assert!(a.on_arc(Point::new(0.0, 1.0)));
+    ///     assert!(!a.on_arc(Point::new(0.0, -1.0)));
+    ///
+    pub fn on_arc(&self, p: Point) -> bool {
+        eq_f64((p - self.rd.o).dis(), self.rd.r) && self.contains_angle((p - self.rd.o).polar_angle())
+    }
+
+    /// 计算圆弧与直线的交点。
+    pub fn inter_line(&self, l: Line) -> Vec<Point> {
+        self.rd.inter_line(l).points().into_iter().filter(|&p| self.on_arc(p)).collect()
+    }
+
+    /// 计算圆弧与线段的交点。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::line::Line;
+    ///     use rust_geometry::round::Round;
+    ///     use rust_geometry::arc::Arc;
+    ///
+    ///     let a = Arc::new(Round::new(Point::new(0.0, 0.0), 1.0), 0.0, std::f64::consts::PI);
+    ///     let l = Line::new(Point::new(-2.0, 0.5), Point::new(0.0, 0.5));
+    ///     let v = a.inter_seg(l);
+    ///     assert_eq!(v, vec![Point::new(-(0.75_f64.sqrt()), 0.5)]);
+    ///
+    pub fn inter_seg(&self, l: Line) -> Vec<Point> {
+        self.inter_line(l).into_iter().filter(|&p| l.on_seg(p)).collect()
+    }
+
+    /// 计算圆弧与圆的交点。若圆弧所在的圆与 `rd` 重合，返回圆弧的两个端点。
+    pub fn inter_round(&self, rd: Round) -> Vec<Point> {
+        match self.rd.inter_round(rd) {
+            Intersection::Coincident => self.endpoints(),
+            res => res.points().into_iter().filter(|&p| self.on_arc(p)).collect(),
+        }
+    }
+
+    /// 计算两段圆弧的交点。若两段圆弧所在的圆重合，返回公共部分的端点。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///     use rust_geometry::arc::Arc;
+    ///
+    ///     let a = Arc::new(Round::new(Point::new(0.0, 0.0), 1.0), 0.0, std::f64::consts::PI);
+    ///     let b = Arc::new(Round::new(Point::new(1.0, 0.0), 1.0), 0.0, std::f64::consts::PI);
+    ///     assert_eq!(a.inter_arc(b), vec![Point::new(0.5, 0.75_f64.sqrt())]);
+    ///
+    pub fn inter_arc(&self, a: Arc) -> Vec<Point> {
+        match self.rd.inter_round(a.rd) {
+            Intersection::Coincident => {
+                let mut res: Vec<Point> = Vec::new();
+                for p in self.endpoints().into_iter().filter(|&p| a.on_arc(p))
+                    .chain(a.endpoints().into_iter().filter(|&p| self.on_arc(p))) {
+                    if !res.contains(&p) {
+                        res.push(p);
+                    }
+                }
+                res
+            }
+            res => res.points().into_iter().filter(|&p| self.on_arc(p) && a.on_arc(p)).collect(),
+        }
+    }
+
+    /// 圆弧的两个端点，整圆时只返回一个点。
+    fn endpoints(&self) -> Vec<Point> {
+        let (a, b) = (self.start_point(), self.end_point());
+        if a == b { vec![a] } else { vec![a, b] }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measure_test() {
+        let rd = Round::new(Point::new(1.0, 1.0), 2.0);
+        let a = Arc::new(rd, -PI / 2.0, PI / 2.0);
+        assert!(eq_f64(a.span(), PI));
+        assert!(eq_f64(a.len(), 2.0 * PI));
+        assert!(eq_f64(a.sector_area(), 2.0 * PI));
+        assert!(eq_f64(a.segment_area(), 2.0 * PI));
+
+        let wrap = Arc::new(rd, 3.0 * PI / 2.0, PI / 2.0);
+        assert!(eq_f64(wrap.span(), PI));
+        assert!(wrap.on_arc(Point::new(3.0, 1.0)));
+        assert!(!wrap.on_arc(Point::new(-1.0, 1.0)));
+
+        let full = Arc::new(rd, 0.0, 2.0 * PI);
+        assert!(eq_f64(full.sector_area(), rd.area()));
+        assert!(full.on_arc(Point::new(-1.0, 1.0)));
+
+        let b = Arc::from_points(rd, Point::new(1.0, 3.0), Point::new(3.0, 1.0));
+        assert!(eq_f64(b.span(), 3.0 * PI / 2.0));
+    }
+
+    #[test]
+    fn inter_test() {
+        let a = Arc::new(Round::new(Point::new(0.0, 0.0), 1.0), 0.0, PI / 2.0);
+        let l = Line::new(Point::new(-1.0, -1.0), Point::new(1.0, 1.0));
+        let h = 0.5_f64.sqrt();
+        assert_eq!(a.inter_line(l), vec![Point::new(h, h)]);
+        assert!(a.inter_seg(Line::new(Point::new(-1.0, -1.0), Point::new(0.0, 0.0))).is_empty());
+
+        let rd = Round::new(Point::new(1.0, 1.0), 1.0);
+        let mut v = a.inter_round(rd);
+        v.sort_by(|p, q| p.x.total_cmp(&q.x));
+        assert_eq!(v, vec![Point::new(0.0, 1.0), Point::new(1.0, 0.0)]);
+
+        // 共圆的两段圆弧，公共部分为 [pi / 4, pi / 2]。
+        let b = Arc::new(Round::new(Point::new(0.0, 0.0), 1.0), PI / 4.0, PI);
+        let mut v = a.inter_arc(b);
+        v.sort_by(|p, q| p.x.total_cmp(&q.x));
+        assert_eq!(v, vec![Point::new(0.0, 1.0), Point::new(h, h)]);
+    }
+}
@@ -3,6 +3,7 @@ use crate::point::*;
 use std::cmp::Ordering::*;
 use std::fmt;
 
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConvexHull {
     pub u_hull: Vec<Point>,
     pub d_hull: Vec<Point>,
@@ -99,8 +100,17 @@ impl ConvexHull {
         d_copy
     }
     
-    /// 凸包求解函数中将点排序用到的比较函数。
-    fn pt_cmp(a: &Point, b: &Point) -> std::cmp::Ordering {
+    /// 凸包求解函数中将点排序用到的比较函数，先比较横坐标再比较纵坐标（均基于 `EPS`），也可用于其他需要对点排序的算法。
+    ///
+    ///     use std::cmp::Ordering;
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::convex_hull::ConvexHull;
+    ///
+    ///     let a = Point::new(0.0, 1.0);
+    ///     let b = Point::new(1.0, 0.0);
+    ///     assert_eq!(ConvexHull::pt_cmp(&a, &b), Ordering::Less);
+    ///
+    pub fn pt_cmp(a: &Point, b: &Point) -> std::cmp::Ordering {
         if !eq_f64(a.x, b.x) {
             if a.x < b.x { Less } else { Greater }
         }
@@ -131,7 +141,7 @@ impl ConvexHull {
         let mut u_hull: Vec<Point> = Vec::new();
         let mut d_hull: Vec<Point> = Vec::new();
     
-        pts.sort_by(|a, b| Self::pt_cmp(a, b));
+        pts.sort_by(Self::pt_cmp);
         for p in pts {
             while ucnt >= 2 && ((u_hull[ucnt - 1] - u_hull[ucnt - 2]) ^ (p - u_hull[ucnt - 2])) > -EPS {
                 u_hull.pop();
@@ -171,4 +181,13 @@ mod tests {
         let stdans = vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(1.0, 1.0), Point::new(0.0, 1.0)];
         assert_eq!(pts, stdans);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_test() {
+        let hull = ConvexHull::get_convex_hull(vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(0.0, 1.0)]);
+        let back: ConvexHull = serde_json::from_str(&serde_json::to_string(&hull).unwrap()).unwrap();
+        assert_eq!(back.u_hull, hull.u_hull);
+        assert_eq!(back.d_hull, hull.d_hull);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,215 @@
+use crate::*;
+use crate::space::*;
+use std::collections::HashSet;
+use std::fmt;
+
+/// `ConvexHull3` 三维凸包，由点集 `pts` 以及凸包表面的三角形面 `faces` 表示。
+///
+/// 每个面存储三个顶点在 `pts` 中的下标，从凸包外部看顶点按逆时针排列，即 `(b - a) ^ (c - a)` 为外法向量。
+/// 共面的表面会被剖分为多个三角形。
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ConvexHull3 {
+    pub pts: Vec<Point3>,
+    pub faces: Vec<[usize; 3]>,
+}
+
+impl fmt::Display for ConvexHull3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "pts {:?} faces {:?}", self.pts, self.faces)
+    }
+}
+
+impl ConvexHull3 {
+    /// 面 `f` 的法向量（未归一化），指向凸包外部。
+    fn normal(pts: &[Point3], f: &[usize; 3]) -> Point3 {
+        (pts[f[1]] - pts[f[0]]) ^ (pts[f[2]] - pts[f[0]])
+    }
+
+    /// 点 `p` 相对面 `f` 的定向，为正表示 `p` 在面的外侧。
+    fn orient(pts: &[Point3], f: &[usize; 3], p: Point3) -> f64 {
+        Self::normal(pts, f) * (p - pts[f[0]])
+    }
+
+    /// 点集包围盒的最大边长。定向的值随坐标尺度的三次方变化，因此判断时使用 `EPS` 乘以该边长的相应次幂作为误差。
+    fn extent(pts: &[Point3]) -> f64 {
+        let axis = |g: fn(&Point3) -> f64| {
+            let (lo, hi) = pts.iter().map(g).fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), x| (lo.min(x), hi.max(x)));
+            if lo <= hi { hi - lo } else { 0.0 }
+        };
+        axis(|p| p.x).max(axis(|p| p.y)).max(axis(|p| p.z))
+    }
+
+    /// 用增量法求出点集的三维凸包，所有点共面（含点数不足 4 个）时返回 `None`。
+    ///
+    /// 先找出一个不退化的四面体，再依次加入其余点：删去该点能看到的面，并用地平线上的边与该点连成新面。
+    /// 位于凸包内部的点会被忽略，表面上的点可能作为共面三角形的顶点保留，时间复杂度 O(n^2)。
+    /// 共线、共面与可见性的判断使用相对于点集尺度的误差，因此结果不随坐标的整体缩放而改变。
+    ///
+    ///     use rust_geometry::eq_f64;
+    ///     use rust_geometry::space::Point3;
+    ///     use rust_geometry::convex_hull3::ConvexHull3;
+    ///
+    ///     let pts = vec![Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0),
+    ///                    Point3::new(0.0, 0.0, 1.0), Point3::new(0.1, 0.1, 0.1)];
+    ///     let hull = ConvexHull3::get_convex_hull(pts).unwrap();
+    ///     assert_eq!(hull.faces.len(), 4);
+    ///     assert!(eq_f64(hull.volume(), 1.0 / 6.0));
+    ///
+    pub fn get_convex_hull(pts: Vec<Point3>) -> Option<ConvexHull3> {
+        let n = pts.len();
+        let len = Self::extent(&pts);
+        let eps = EPS * len * len * len;
+        let i1 = (1 .. n).find(|&i| pts[i] != pts[0])?;
+        let i2 = (1 .. n).find(|&i| ((pts[i1] - pts[0]) ^ (pts[i] - pts[0])).dis() > EPS * len * len)?;
+        let i3 = (1 .. n).find(|&i| Self::orient(&pts, &[0, i1, i2], pts[i]).abs() > eps)?;
+
+        let mut faces: Vec<[usize; 3]> = Vec::new();
+        let tetra = [0, i1, i2, i3];
+        for k in 0 .. 4 {
+            let mut f = [tetra[(k + 1) % 4], tetra[(k + 2) % 4], tetra[(k + 3) % 4]];
+            if Self::orient(&pts, &f, pts[tetra[k]]) > 0.0 {
+                f.swap(1, 2);
+            }
+            faces.push(f);
+        }
+
+        for i in 0 .. n {
+            if tetra.contains(&i) {
+                continue
+            }
+            let visible: Vec<bool> = faces.iter().map(|f| Self::orient(&pts, f, pts[i]) > eps).collect();
+            if !visible.contains(&true) {
+                continue
+            }
+
+            // 按面的顺序收集可见面的边，使新面的顺序只由输入决定。
+            let edges: Vec<(usize, usize)> = faces.iter().zip(&visible).filter(|(_, &v)| v)
+                .flat_map(|(f, _)| (0 .. 3).map(move |k| (f[k], f[(k + 1) % 3]))).collect();
+            let lookup: HashSet<(usize, usize)> = edges.iter().copied().collect();
+            let mut next: Vec<[usize; 3]> = faces.iter().zip(&visible).filter(|(_, &v)| !v)
+                .map(|(&f, _)| f).collect();
+            // 地平线上的边只属于一个可见面，它的反向边属于不可见面。
+            for &(u, v) in &edges {
+                if !lookup.contains(&(v, u)) {
+                    next.push([u, v, i]);
+                }
+            }
+            faces = next;
+        }
+
+        Some(ConvexHull3 { pts, faces })
+    }
+
+    /// 凸包顶点在 `pts` 中的下标，按升序排列。
+    pub fn vertices(&self) -> Vec<usize> {
+        let mut v: Vec<usize> = self.faces.iter().flatten().copied().collect();
+        v.sort_unstable();
+        v.dedup();
+        v
+    }
+
+    /// 凸包的体积，由各面与原点构成的有向四面体体积求和得到。
+    pub fn volume(&self) -> f64 {
+        self.faces.iter().map(|f| self.pts[f[0]] * (self.pts[f[1]] ^ self.pts[f[2]])).sum::<f64>() / 6.0
+    }
+
+    /// 凸包的表面积。
+    pub fn surface_area(&self) -> f64 {
+        self.faces.iter().map(|f| Self::normal(&self.pts, f).dis()).sum::<f64>() / 2.0
+    }
+
+    /// 判断点是否在凸包内（含表面，基于 `EPS` 与点集的尺度）。
+    ///
+    ///     use rust_geometry::space::Point3;
+    ///     use rust_geometry::convex_hull3::ConvexHull3;
+    ///
+    ///     let pts = vec![Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0),
+    ///                    Point3::new(0.0, 0.0, 1.0)];
+    ///     let hull = ConvexHull3::get_convex_hull(pts).unwrap();
+    ///     assert!(hull.contains(Point3::new(0.2, 0.2, 0.2)));
+    ///     assert!(hull.contains(Point3::new(0.5, 0.5, 0.0)));
+    ///     assert!(!hull.contains(Point3::new(0.5, 0.5, 0.5)));
+    ///
+    pub fn contains(&self, p: Point3) -> bool {
+        let eps = EPS * Self::extent(&self.pts).powi(3);
+        self.faces.iter().all(|f| Self::orient(&self.pts, f, p) <= eps)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Lcg;
+
+    #[test]
+    fn cube_test() {
+        let mut pts: Vec<Point3> = Vec::new();
+        for x in 0 .. 3 {
+            for y in 0 .. 3 {
+                for z in 0 .. 3 {
+                    pts.push(Point3::new(x as f64 / 2.0, y as f64 / 2.0, z as f64 / 2.0));
+                }
+            }
+        }
+        pts.push(Point3::new(0.5, 0.5, 0.5));
+        let hull = ConvexHull3::get_convex_hull(pts).unwrap();
+        assert!(eq_f64(hull.volume(), 1.0));
+        assert!(eq_f64(hull.surface_area(), 6.0));
+        assert!(!hull.vertices().contains(&27));
+        let corners = vec![Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0),
+                           Point3::new(1.0, 1.0, 0.0), Point3::new(0.0, 0.0, 1.0), Point3::new(1.0, 0.0, 1.0),
+                           Point3::new(0.0, 1.0, 1.0), Point3::new(1.0, 1.0, 1.0), Point3::new(0.5, 0.5, 0.5)];
+        let hull = ConvexHull3::get_convex_hull(corners).unwrap();
+        assert_eq!(hull.vertices().len(), 8);
+        assert_eq!(hull.faces.len(), 12);
+
+        let flat = vec![Point3::new(0.0, 0.0, 1.0), Point3::new(1.0, 0.0, 1.0),
+                        Point3::new(0.0, 1.0, 1.0), Point3::new(1.0, 1.0, 1.0)];
+        assert!(ConvexHull3::get_convex_hull(flat).is_none());
+    }
+
+    #[test]
+    fn lattice_scale_test() {
+        // 6x6x6 的格点立方体，在各种尺度下结果都应当一致。
+        for step in [1e-6, 1e-4, 1e-3, 1.0, 1e3, 1e6] {
+            let mut pts: Vec<Point3> = Vec::new();
+            for x in 0 .. 6 {
+                for y in 0 .. 6 {
+                    for z in 0 .. 6 {
+                        pts.push(Point3::new(x as f64 * step, y as f64 * step, z as f64 * step));
+                    }
+                }
+            }
+            let hull = ConvexHull3::get_convex_hull(pts.clone()).unwrap();
+            let (v, f) = (hull.vertices().len(), hull.faces.len());
+            assert_eq!(v + f / 2, 2 + f);
+            let side = 5.0 * step;
+            assert!((hull.volume() / side.powi(3) - 1.0).abs() < 1e-9);
+            assert!((hull.surface_area() / (6.0 * side * side) - 1.0).abs() < 1e-9);
+            for p in &pts {
+                assert!(hull.contains(*p));
+            }
+            assert!(!hull.contains(Point3::new(side * 1.01, side / 2.0, side / 2.0)));
+            assert!(!hull.contains(Point3::new(-side * 0.01, side / 2.0, side / 2.0)));
+        }
+    }
+
+    #[test]
+    fn random_test() {
+        let mut rng = Lcg::new(7);
+        let mut rnd = || rng.below(1000) as f64 / 100.0;
+        let pts: Vec<Point3> = (0 .. 300).map(|_| Point3::new(rnd(), rnd(), rnd())).collect();
+        let hull = ConvexHull3::get_convex_hull(pts.clone()).unwrap();
+
+        // 欧拉公式：V - E + F = 2，三角剖分的表面满足 E = 3F / 2。
+        let (v, f) = (hull.vertices().len(), hull.faces.len());
+        assert_eq!(v + f / 2, 2 + f);
+        for p in &pts {
+            assert!(hull.contains(*p));
+        }
+        assert!(!hull.contains(Point3::new(-0.1, 5.0, 5.0)));
+        assert!(hull.volume() > 0.0 && hull.volume() < 1000.0);
+        // 相同的输入得到相同顺序的面。
+        assert_eq!(ConvexHull3::get_convex_hull(pts).unwrap().faces, hull.faces);
+    }
+}
@@ -0,0 +1,343 @@
+use crate::*;
+use crate::point::*;
+use crate::convex_hull::*;
+
+/// 四边形边（quad-edge）结构中的一条有向边。
+///
+/// 每条无向边对应连续的四条记录，编号为 `4k .. 4k + 3`，其中 `4k` 与 `4k + 2` 互为反向边，
+/// `4k + 1` 与 `4k + 3` 为对偶边。`o` 为绕起点逆时针的下一条边，`p` 为起点在点集中的下标。
+#[derive(Debug, Copy, Clone)]
+struct QuadEdge {
+    o: usize,
+    p: usize,
+}
+
+/// Guibas-Stolfi 分治法求 Delaunay 三角剖分时使用的边池。
+struct QuadEdges<'a> {
+    pts: &'a [Point],
+    edges: Vec<QuadEdge>,
+    alive: Vec<bool>,
+}
+
+impl<'a> QuadEdges<'a> {
+    fn new(pts: &'a [Point]) -> Self {
+        QuadEdges { pts, edges: Vec::new(), alive: Vec::new() }
+    }
+
+    fn rot(e: usize) -> usize {
+        (e & !3) | ((e + 1) & 3)
+    }
+
+    fn r(e: usize) -> usize {
+        e ^ 2
+    }
+
+    fn o(&self, e: usize) -> usize {
+        self.edges[e].o
+    }
+
+    fn prev(&self, e: usize) -> usize {
+        Self::rot(self.o(Self::rot(e)))
+    }
+
+    fn next(&self, e: usize) -> usize {
+        self.prev(Self::r(e))
+    }
+
+    /// 边的起点。
+    fn p(&self, e: usize) -> Point {
+        self.pts[self.edges[e].p]
+    }
+
+    /// 边的终点。
+    fn f(&self, e: usize) -> Point {
+        self.pts[self.edges[Self::r(e)].p]
+    }
+
+    fn make_edge(&mut self, orig: usize, dest: usize) -> usize {
+        let e = self.edges.len();
+        self.edges.push(QuadEdge { o: e, p: orig });
+        self.edges.push(QuadEdge { o: e + 3, p: NIL });
+        self.edges.push(QuadEdge { o: e + 2, p: dest });
+        self.edges.push(QuadEdge { o: e + 1, p: NIL });
+        self.alive.push(true);
+        e
+    }
+
+    fn splice(&mut self, a: usize, b: usize) {
+        let alpha = Self::rot(self.o(a));
+        let beta = Self::rot(self.o(b));
+        self.swap_o(alpha, beta);
+        self.swap_o(a, b);
+    }
+
+    fn swap_o(&mut self, a: usize, b: usize) {
+        let t = self.edges[a].o;
+        self.edges[a].o = self.edges[b].o;
+        self.edges[b].o = t;
+    }
+
+    fn connect(&mut self, a: usize, b: usize) -> usize {
+        let q = self.make_edge(self.edges[Self::r(a)].p, self.edges[b].p);
+        let an = self.next(a);
+        self.splice(q, an);
+        self.splice(Self::r(q), b);
+        q
+    }
+
+    fn delete(&mut self, e: usize) {
+        let ep = self.prev(e);
+        self.splice(e, ep);
+        let re = Self::r(e);
+        let rep = self.prev(re);
+        self.splice(re, rep);
+        self.alive[e >> 2] = false;
+    }
+
+    /// 判断 `p` 是否严格位于 `a`、`b`、`c` 的外接圆内（`a`、`b`、`c` 逆时针）。
+    fn circ(p: Point, a: Point, b: Point, c: Point) -> bool {
+        let p2 = p.sqrdis();
+        let (da, db, dc) = (a.sqrdis() - p2, b.sqrdis() - p2, c.sqrdis() - p2);
+        ((a - p) ^ (b - p)) * dc + ((b - p) ^ (c - p)) * da + ((c - p) ^ (a - p)) * db > EPS
+    }
+
+    /// 对已排序且去重的点 `ids[..]` 递归求三角剖分，返回凸包上最左点出发的逆时针边与最右点出发的顺时针边。
+    fn rec(&mut self, ids: &[usize]) -> (usize, usize) {
+        let n = ids.len();
+        if n <= 3 {
+            let a = self.make_edge(ids[0], ids[1]);
+            if n == 2 {
+                return (a, Self::r(a));
+            }
+            let b = self.make_edge(ids[1], ids[2]);
+            self.splice(Self::r(a), b);
+            let (p0, p1, p2) = (self.pts[ids[0]], self.pts[ids[1]], self.pts[ids[2]]);
+            let side = (p1 - p0) ^ (p2 - p0);
+            if eq_f64(side, 0.0) {
+                return (a, Self::r(b));
+            }
+            let c = self.connect(b, a);
+            return if side < 0.0 { (Self::r(c), c) } else { (a, Self::r(b)) };
+        }
+
+        let half = n / 2;
+        let (mut ra, mut a) = self.rec(&ids[.. n - half]);
+        let (mut b, mut rb) = self.rec(&ids[n - half ..]);
+        loop {
+            if ((self.f(a) - self.p(b)) ^ (self.p(a) - self.p(b))) < -EPS {
+                a = self.next(a);
+            }
+            else if ((self.f(b) - self.p(a)) ^ (self.p(b) - self.p(a))) > EPS {
+                b = self.o(Self::r(b));
+            }
+            else {
+                break;
+            }
+        }
+        let mut base = self.connect(Self::r(b), a);
+        if self.edges[a].p == self.edges[ra].p {
+            ra = Self::r(base);
+        }
+        if self.edges[b].p == self.edges[rb].p {
+            rb = base;
+        }
+
+        loop {
+            let mut lc = self.o(Self::r(base));
+            if self.valid(lc, base) {
+                while Self::circ(self.f(self.o(lc)), self.f(base), self.p(base), self.f(lc)) {
+                    let t = self.o(lc);
+                    self.delete(lc);
+                    lc = t;
+                }
+            }
+            let mut rc = self.prev(base);
+            if self.valid(rc, base) {
+                while Self::circ(self.f(self.prev(rc)), self.f(base), self.p(base), self.f(rc)) {
+                    let t = self.prev(rc);
+                    self.delete(rc);
+                    rc = t;
+                }
+            }
+            let (vl, vr) = (self.valid(lc, base), self.valid(rc, base));
+            if !vl && !vr {
+                break;
+            }
+            if !vl || (vr && Self::circ(self.f(rc), self.p(rc), self.f(lc), self.p(lc))) {
+                base = self.connect(rc, Self::r(base));
+            }
+            else {
+                base = self.connect(Self::r(base), Self::r(lc));
+            }
+        }
+        (ra, rb)
+    }
+
+    /// 判断边 `e` 的终点是否位于 `base` 的上方，即能否作为合并时的候选边。
+    fn valid(&self, e: usize, base: usize) -> bool {
+        let x = self.f(e);
+        ((self.f(base) - x) ^ (self.p(base) - x)) > EPS
+    }
+
+    /// 剖分中所有存活的无向边，以两端点在点集中的下标表示。
+    fn live_edges(&self) -> Vec<(usize, usize)> {
+        (0 .. self.alive.len())
+            .filter(|&k| self.alive[k])
+            .map(|k| (self.edges[k << 2].p, self.edges[(k << 2) | 2].p))
+            .collect()
+    }
+}
+
+/// 计算点集 Delaunay 三角剖分的所有边，返回点在输入中的下标对，时间复杂度 O(n log n)。
+///
+/// 重合的点（在 `EPS` 意义下相等）只有其中一个参与剖分，其余点不会出现在结果中；所有点共线时返回相邻点相连的链。
+///
+///     use rust_geometry::point::Point;
+///     use rust_geometry::delaunay::delaunay_edges;
+///
+///     let pts = vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(0.0, 1.0), Point::new(1.0, 1.1)];
+///     let edges = delaunay_edges(&pts);
+///     assert_eq!(edges.len(), 5);
+///
+pub fn delaunay_edges(pts: &[Point]) -> Vec<(usize, usize)> {
+    let ids = sorted_unique(pts).0;
+    if ids.len() < 2 {
+        return Vec::new()
+    }
+
+    let mut qe = QuadEdges::new(pts);
+    qe.rec(&ids);
+    qe.live_edges()
+}
+
+/// 将点的下标按坐标排序并去重，返回去重后的下标以及被去掉的点与保留点之间的下标对。
+fn sorted_unique(pts: &[Point]) -> (Vec<usize>, Vec<(usize, usize)>) {
+    let mut ids: Vec<usize> = (0 .. pts.len()).collect();
+    ids.sort_by(|&i, &j| ConvexHull::pt_cmp(&pts[i], &pts[j]));
+
+    let mut uniq: Vec<usize> = Vec::new();
+    let mut dup = Vec::new();
+    for i in ids {
+        match uniq.last() {
+            Some(&j) if pts[i] == pts[j] => dup.push((j, i)),
+            _ => uniq.push(i),
+        }
+    }
+    (uniq, dup)
+}
+
+/// 并查集的查找操作（带路径压缩）。
+fn find(fa: &mut [usize], mut x: usize) -> usize {
+    while fa[x] != x {
+        fa[x] = fa[fa[x]];
+        x = fa[x];
+    }
+    x
+}
+
+/// 计算点集的欧几里得最小生成树，返回树边（点在输入中的下标对）及总长度。
+///
+/// 最小生成树的边一定是 Delaunay 三角剖分的边，因此先求剖分再对其 O(n) 条边做 Kruskal，总时间复杂度 O(n log n)。
+///
+///     use rust_geometry::eq_f64;
+///     use rust_geometry::point::Point;
+///     use rust_geometry::delaunay::euclidean_mst;
+///
+///     let pts = vec![Point::new(0.0, 0.0), Point::new(3.0, 0.0), Point::new(0.0, 4.0), Point::new(3.0, 4.0)];
+///     let (edges, len) = euclidean_mst(&pts);
+///     assert_eq!(edges.len(), 3);
+///     assert!(eq_f64(len, 10.0));
+///
+pub fn euclidean_mst(pts: &[Point]) -> (Vec<(usize, usize)>, f64) {
+    let (ids, dup) = sorted_unique(pts);
+    let mut cand: Vec<(usize, usize)> = if ids.len() >= 2 {
+        let mut qe = QuadEdges::new(pts);
+        qe.rec(&ids);
+        qe.live_edges()
+    }
+    else {
+        Vec::new()
+    };
+    cand.sort_by(|a, b| {
+        let da = (pts[a.0] - pts[a.1]).sqrdis();
+        let db = (pts[b.0] - pts[b.1]).sqrdis();
+        da.total_cmp(&db)
+    });
+
+    let mut fa: Vec<usize> = (0 .. pts.len()).collect();
+    let mut edges = dup;
+    for &(u, v) in edges.iter() {
+        fa[v] = u;
+    }
+    let mut total = 0.0;
+    for (u, v) in cand {
+        let (fu, fv) = (find(&mut fa, u), find(&mut fa, v));
+        if fu != fv {
+            fa[fu] = fv;
+            total += (pts[u] - pts[v]).dis();
+            edges.push((u, v));
+        }
+    }
+    (edges, total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Lcg;
+
+    /// O(n^2) 的 Prim 算法，用于检验结果。
+    fn prim(pts: &[Point]) -> f64 {
+        let n = pts.len();
+        let mut dis = vec![f64::INFINITY; n];
+        let mut used = vec![false; n];
+        let mut total = 0.0;
+        dis[0] = 0.0;
+        for _ in 0 .. n {
+            let u = (0 .. n).filter(|&i| !used[i])
+                .min_by(|&i, &j| dis[i].partial_cmp(&dis[j]).unwrap()).unwrap();
+            used[u] = true;
+            total += dis[u];
+            for v in 0 .. n {
+                let d = (pts[u] - pts[v]).dis();
+                if !used[v] && d < dis[v] {
+                    dis[v] = d;
+                }
+            }
+        }
+        total
+    }
+
+    fn pseudo_random_points(n: usize, seed: u64, modulo: u64) -> Vec<Point> {
+        let mut rng = Lcg::new(seed);
+        (0 .. n).map(|_| Point::new(rng.below(modulo) as f64, rng.below(modulo) as f64)).collect()
+    }
+
+    #[test]
+    fn mst_random_test() {
+        for (seed, modulo) in [(1, 1000), (2, 20), (3, 5), (4, 1_000_000)] {
+            let pts = pseudo_random_points(300, seed, modulo);
+            let (edges, len) = euclidean_mst(&pts);
+            assert_eq!(edges.len(), pts.len() - 1);
+            assert!((len - prim(&pts)).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn collinear_test() {
+        let pts: Vec<Point> = (0 .. 10).map(|i| Point::new(i as f64, 2.0 * i as f64)).collect();
+        let edges = delaunay_edges(&pts);
+        assert_eq!(edges.len(), 9);
+        let (_, len) = euclidean_mst(&pts);
+        assert!(eq_f64(len, 9.0 * 5.0_f64.sqrt()));
+    }
+
+    #[test]
+    fn degenerate_test() {
+        assert_eq!(euclidean_mst(&[]).0.len(), 0);
+        let pts = vec![Point::new(1.0, 1.0); 3];
+        let (edges, len) = euclidean_mst(&pts);
+        assert_eq!(edges.len(), 2);
+        assert!(eq_f64(len, 0.0));
+    }
+}
@@ -0,0 +1,288 @@
+use crate::*;
+use crate::point::*;
+use crate::line::*;
+use crate::round::*;
+use std::f64::consts::PI;
+use std::fmt;
+
+/// `Ellipse` 椭圆，由中心 `o`、两条半轴长 `a`、`b` 以及 `a` 所在轴相对 x 轴逆时针旋转的角度 `theta`（弧度制）表示。
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ellipse {
+    pub o: Point,
+    pub a: f64,
+    pub b: f64,
+    pub theta: f64,
+}
+
+impl fmt::Display for Ellipse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{} {} {} {}]", self.o, self.a, self.b, self.theta)
+    }
+}
+
+impl Ellipse {
+    /// 通过中心、两条半轴长与旋转角构造椭圆。
+    pub fn new(o: Point, a: f64, b: f64, theta: f64) -> Self {
+        Ellipse { o, a, b, theta }
+    }
+
+    /// 将圆看作两条半轴相等的椭圆。
+    pub fn from_round(rd: Round) -> Self {
+        Ellipse { o: rd.o, a: rd.r, b: rd.r, theta: 0.0 }
+    }
+
+    /// 计算圆在仿射变换 `p -> m * p + t` 下的像，`m` 为按行给出的 2x2 矩阵。
+    ///
+    /// 圆的像为椭圆，半轴长为半径乘以 `m` 的两个奇异值，即 `m * m^T` 特征值的平方根。
+    ///
+    ///     use rust_geometry::eq_f64;
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///     use rust_geometry::ellipse::Ellipse;
+    ///
+    ///     let rd = Round::new(Point::new(1.0, 1.0), 1.0);
+    ///     let e = Ellipse::from_round_affine(rd, [[2.0, 0.0], [0.0, 3.0]], Point::new(0.0, 0.0));
+    ///     assert_eq!(e.o, Point::new(2.0, 3.0));
+    ///     assert!(eq_f64(e.area(), 6.0 * std::f64::consts::PI));
+    ///
+    pub fn from_round_affine(rd: Round, m: [[f64; 2]; 2], t: Point) -> Self {
+        let apply = |p: Point| Point::new(m[0][0] * p.x + m[0][1] * p.y, m[1][0] * p.x + m[1][1] * p.y);
+        let p = m[0][0] * m[0][0] + m[0][1] * m[0][1];
+        let s = m[1][0] * m[1][0] + m[1][1] * m[1][1];
+        let q = m[0][0] * m[1][0] + m[0][1] * m[1][1];
+        let h = (((p - s) / 2.0).powi(2) + q * q).sqrt();
+        let (l1, l2) = ((p + s) / 2.0 + h, ((p + s) / 2.0 - h).max(0.0));
+        Ellipse {
+            o: apply(rd.o) + t,
+            a: rd.r * l1.sqrt(),
+            b: rd.r * l2.sqrt(),
+            theta: (2.0 * q).atan2(p - s) / 2.0,
+        }
+    }
+
+    /// 将点转换到以椭圆中心为原点、半轴为坐标轴的局部坐标系中。
+    pub fn to_local(&self, p: Point) -> Point {
+        (p - self.o).rot(-self.theta)
+    }
+
+    /// 将局部坐标系中的点转换回全局坐标。
+    pub fn to_global(&self, p: Point) -> Point {
+        self.o + p.rot(self.theta)
+    }
+
+    /// 参数角为 `t` 的椭圆上的点。
+    pub fn point_at(&self, t: f64) -> Point {
+        self.to_global(Point::new(self.a * t.cos(), self.b * t.sin()))
+    }
+
+    /// 判断点是否在椭圆内（含边界，基于 `EPS`）。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::ellipse::Ellipse;
+    ///
+    ///     let e = Ellipse::new(Point::new(0.0, 0.0), 2.0, 1.0, std::f64::consts::PI / 2.0);
+    ///     assert!(e.contains(Point::new(0.0, 2.0)));
+    ///     assert!(!e.contains(Point::new(2.0, 0.0)));
+    ///
+    pub fn contains(&self, p: Point) -> bool {
+        let q = self.to_local(p);
+        (q.x / self.a).powi(2) + (q.y / self.b).powi(2) < 1.0 + EPS
+    }
+
+    /// 椭圆的面积。
+    pub fn area(&self) -> f64 {
+        PI * self.a * self.b
+    }
+
+    /// 椭圆周长的近似值，使用 Ramanujan 第二近似公式，相对误差约为 h^5 量级。
+    pub fn perimeter(&self) -> f64 {
+        let h = ((self.a - self.b) / (self.a + self.b)).powi(2);
+        PI * (self.a + self.b) * (1.0 + 3.0 * h / (10.0 + (4.0 - 3.0 * h).sqrt()))
+    }
+
+    /// 计算椭圆与直线的交点。
+    ///
+    /// 在局部坐标系中把两轴分别缩放为单位长度，椭圆变为单位圆，直线仍为直线，求交后再变换回来。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::line::Line;
+    ///     use rust_geometry::ellipse::Ellipse;
+    ///
+    ///     let e = Ellipse::new(Point::new(0.0, 0.0), 2.0, 1.0, 0.0);
+    ///     let l = Line::new(Point::new(0.0, -5.0), Point::new(0.0, 5.0));
+    ///     let v = e.inter_line(l);
+    ///     assert_eq!(v, vec![Point::new(0.0, 1.0), Point::new(0.0, -1.0)]);
+    ///
+    pub fn inter_line(&self, l: Line) -> Vec<Point> {
+        let unit = |p: Point| {
+            let q = self.to_local(p);
+            Point::new(q.x / self.a, q.y / self.b)
+        };
+        let back = |p: Point| self.to_global(Point::new(p.x * self.a, p.y * self.b));
+        Round::new(Point::new(0.0, 0.0), 1.0).inter_line(Line::new(unit(l.a), unit(l.b)))
+            .points().into_iter().map(back).collect()
+    }
+
+    /// 计算椭圆与圆的交点（至多 4 个）。
+    ///
+    /// 在局部坐标系中以 `t = tan((s - s0) / 2)` 代入椭圆的参数方程 `(a cos s, b sin s)`，交点条件化为关于 `t` 的四次方程。
+    /// `s0 + pi` 取离圆最远的采样点，使 `t` 趋于无穷的点不是交点。方程的实根由导函数的零点逐一隔离后二分求出，
+    /// 因此相距很近的两个交点也能区分；在导函数零点处与圆的距离差不超过 `EPS` 时视为相切，只计一个交点。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///     use rust_geometry::ellipse::Ellipse;
+    ///
+    ///     let e = Ellipse::new(Point::new(0.0, 0.0), 2.0, 1.0, 0.0);
+    ///     assert_eq!(e.inter_round(Round::new(Point::new(0.0, 0.0), 1.5)).len(), 4);
+    ///     assert_eq!(e.inter_round(Round::new(Point::new(0.0, 0.0), 1.0)).len(), 2);
+    ///
+    pub fn inter_round(&self, rd: Round) -> Vec<Point> {
+        let c = self.to_local(rd.o);
+        let f = |s: f64| (Point::new(self.a * s.cos(), self.b * s.sin()) - c).dis() - rd.r;
+        let s0 = (0 .. 8).map(|i| i as f64 * PI / 4.0)
+            .max_by(|&x, &y| f(x + PI).abs().total_cmp(&f(y + PI).abs())).unwrap();
+
+        // 乘以 (1 + t^2) 后，x - cx、y - cy 与 1 + t^2 都是 t 的二次多项式（按升幂存储）。
+        let (cs, sn) = (s0.cos(), s0.sin());
+        let u = [self.a * cs - c.x, -2.0 * self.a * sn, -self.a * cs - c.x];
+        let v = [self.b * sn - c.y, 2.0 * self.b * cs, -self.b * sn - c.y];
+        let d = [1.0, 0.0, 1.0];
+        let mut poly = [0.0; 5];
+        for i in 0 .. 3 {
+            for j in 0 .. 3 {
+                poly[i + j] += u[i] * u[j] + v[i] * v[j] - rd.r * rd.r * d[i] * d[j];
+            }
+        }
+
+        let point = |t: f64| {
+            let s = s0 + 2.0 * t.atan();
+            Point::new(self.a * s.cos(), self.b * s.sin())
+        };
+        poly_roots(&poly, &|t| eq_f64((point(t) - c).dis(), rd.r))
+            .into_iter().map(|t| self.to_global(point(t))).collect()
+    }
+}
+
+/// 按升幂存储的多项式在 `x` 处的值。
+fn poly_eval(c: &[f64], x: f64) -> f64 {
+    c.iter().rev().fold(0.0, |acc, &k| acc * x + k)
+}
+
+/// 求按升幂存储的多项式的全部实根，按从小到大的顺序返回。
+///
+/// 导函数的零点把实轴分成若干单调区间，每个区间内至多一个根，对端点异号的区间二分求根。
+/// 导函数零点 `x` 处满足 `touch(x)` 时视为重根，只返回 `x`，并不再在相邻的区间内求根。
+fn poly_roots(c: &[f64], touch: &dyn Fn(f64) -> bool) -> Vec<f64> {
+    let n = match c.iter().rposition(|&k| k != 0.0) {
+        Some(n) if n > 0 => n,
+        _ => return Vec::new(),
+    };
+    let c = &c[..= n];
+    if n == 1 {
+        return vec![-c[0] / c[1]]
+    }
+    let deriv: Vec<f64> = (1 ..= n).map(|i| c[i] * i as f64).collect();
+    let crit = poly_roots(&deriv, &|x| poly_eval(&deriv, x) == 0.0);
+    let bound = 1.0 + c[.. n].iter().map(|k| (k / c[n]).abs()).fold(0.0, f64::max);
+
+    let mut xs = vec![-bound];
+    xs.extend(crit);
+    xs.push(bound);
+    let ys: Vec<f64> = xs.iter().map(|&x| poly_eval(c, x)).collect();
+    let touched: Vec<bool> = (0 .. xs.len()).map(|i| 0 < i && i + 1 < xs.len() && (ys[i] == 0.0 || touch(xs[i]))).collect();
+
+    let mut roots = Vec::new();
+    for i in 0 .. xs.len() {
+        if touched[i] {
+            roots.push(xs[i]);
+        }
+        if i + 1 == xs.len() || touched[i] || touched[i + 1] || (ys[i] < 0.0) == (ys[i + 1] < 0.0) {
+            continue
+        }
+        let (mut l, mut r) = (xs[i], xs[i + 1]);
+        for _ in 0 .. 200 {
+            let m = (l + r) / 2.0;
+            if m == l || m == r {
+                break
+            }
+            if (poly_eval(c, m) < 0.0) == (ys[i] < 0.0) { l = m } else { r = m }
+        }
+        roots.push((l + r) / 2.0);
+    }
+    roots
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measure_test() {
+        let e = Ellipse::new(Point::new(1.0, 2.0), 3.0, 1.0, PI / 6.0);
+        assert!(eq_f64(e.area(), 3.0 * PI));
+        assert!((e.perimeter() - 13.364_893_220_555_258).abs() < 1e-6);
+        assert!(eq_f64(Ellipse::from_round(Round::new(e.o, 2.0)).perimeter(), 4.0 * PI));
+        assert!(e.contains(e.point_at(1.0)));
+        assert!(e.contains(e.o));
+        assert!(!e.contains(e.o + Point::new(0.0, 3.0)));
+
+        // 旋转 30 度后沿 x 方向拉伸 2 倍。
+        let (c, s) = ((PI / 6.0).cos(), (PI / 6.0).sin());
+        let m = [[2.0 * c, -2.0 * s], [s, c]];
+        let f = Ellipse::from_round_affine(Round::new(Point::new(0.0, 0.0), 1.0), m, Point::new(1.0, 1.0));
+        assert!(eq_f64(f.a, 2.0) && eq_f64(f.b, 1.0));
+        for t in [0.0_f64, 1.0, 2.0, 4.0] {
+            let p = Point::new(t.cos(), t.sin());
+            let q = Point::new(m[0][0] * p.x + m[0][1] * p.y, m[1][0] * p.x + m[1][1] * p.y) + Point::new(1.0, 1.0);
+            let l = f.to_local(q);
+            assert!(eq_f64((l.x / f.a).powi(2) + (l.y / f.b).powi(2), 1.0));
+        }
+    }
+
+    #[test]
+    fn inter_test() {
+        let e = Ellipse::new(Point::new(1.0, -1.0), 3.0, 2.0, 0.7);
+        let l = Line::new(Point::new(-5.0, 0.0), Point::new(5.0, 1.0));
+        let v = e.inter_line(l);
+        assert_eq!(v.len(), 2);
+        for p in v {
+            let q = e.to_local(p);
+            assert!(eq_f64((q.x / e.a).powi(2) + (q.y / e.b).powi(2), 1.0));
+            assert!(eq_f64((l.proj(p) - p).dis(), 0.0));
+        }
+
+        let cases = [(Round::new(Point::new(4.0, 2.0), 2.5), 2), (Round::new(Point::new(1.0, -1.0), 2.5), 4),
+                     (Round::new(Point::new(1.0, -1.0), 3.0), 2), (Round::new(Point::new(10.0, 0.0), 1.0), 0)];
+        for (rd, cnt) in cases {
+            let v = e.inter_round(rd);
+            assert_eq!(v.len(), cnt);
+            for p in v {
+                assert!((((p - rd.o).dis()) - rd.r).abs() < 1e-6);
+            }
+        }
+
+        // 圆心在椭圆上的小圆跨越边界，与椭圆恰有两个相距很近的交点。
+        let e = Ellipse::new(Point::new(0.0, 0.0), 3.0, 1.0, 0.0);
+        for r in [1e-1, 1e-3, 1e-4, 1e-6] {
+            let rd = Round::new(e.point_at(0.4), r);
+            let v = e.inter_round(rd);
+            assert_eq!(v.len(), 2);
+            for p in v {
+                assert!(eq_f64((p - rd.o).dis(), r));
+                assert!(eq_f64((p.x / 3.0).powi(2) + p.y.powi(2), 1.0));
+            }
+        }
+
+        // 与椭圆内切、外切的圆只有一个交点（长轴端点处的曲率半径为 1/3）。
+        for (rd, p) in [(Round::new(Point::new(-2.8, 0.0), 0.2), Point::new(-3.0, 0.0)),
+                        (Round::new(Point::new(4.0, 0.0), 1.0), Point::new(3.0, 0.0)),
+                        (Round::new(Point::new(0.0, 2.5), 1.5), Point::new(0.0, 1.0))] {
+            let v = e.inter_round(rd);
+            assert_eq!(v.len(), 1);
+            assert!(eq_f64((v[0] - p).dis(), 0.0));
+        }
+        assert_eq!(e.inter_round(Round::new(Point::new(-2.0, 0.0), 1.0)).len(), 3);
+    }
+}
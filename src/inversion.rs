@@ -0,0 +1,136 @@
+use crate::*;
+use crate::point::*;
+use crate::line::*;
+use crate::round::*;
+
+/// 直线或圆在反演变换下的像。
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InversionImage {
+    /// 像为直线（原图形为过反演中心的直线或圆）。
+    Line(Line),
+    /// 像为圆（原图形为不过反演中心的直线或圆）。
+    Round(Round),
+}
+
+impl Round {
+    /// 计算点关于该圆的反演点，反演中心本身没有像，返回 `None`。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///
+    ///     let rd = Round::new(Point::new(0.0, 0.0), 2.0);
+    ///     assert_eq!(rd.invert_point(Point::new(1.0, 0.0)), Some(Point::new(4.0, 0.0)));
+    ///     assert_eq!(rd.invert_point(Point::new(0.0, 0.0)), None);
+    ///
+    pub fn invert_point(&self, p: Point) -> Option<Point> {
+        let v = p - self.o;
+        if eq_f64(v.sqrdis(), 0.0) {
+            return None
+        }
+        Some(self.o + v * (self.r * self.r / v.sqrdis()))
+    }
+
+    /// 计算直线关于该圆的反演像：过反演中心的直线仍为自身，否则为过反演中心的圆。
+    ///
+    ///     use rust_geometry::eq_f64;
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::line::Line;
+    ///     use rust_geometry::round::Round;
+    ///     use rust_geometry::inversion::InversionImage;
+    ///
+    ///     let rd = Round::new(Point::new(0.0, 0.0), 2.0);
+    ///     let l = Line::new(Point::new(2.0, -1.0), Point::new(2.0, 1.0));
+    ///     match rd.invert_line(l) {
+    ///         InversionImage::Round(c) => {
+    ///             assert_eq!(c.o, Point::new(1.0, 0.0));
+    ///             assert!(eq_f64(c.r, 1.0));
+    ///         }
+    ///         InversionImage::Line(_) => panic!("unexpected result"),
+    ///     }
+    ///
+    pub fn invert_line(&self, l: Line) -> InversionImage {
+        let f = l.proj(self.o);
+        match self.invert_point(f) {
+            None => InversionImage::Line(l),
+            Some(g) => InversionImage::Round(Round::new((self.o + g) / 2.0, (g - self.o).dis() / 2.0)),
+        }
+    }
+
+    /// 计算圆关于该圆的反演像：过反演中心的圆变为直线，否则仍为圆。
+    ///
+    /// 不过反演中心的圆，其像以原圆在反演中心与圆心连线上的两点的像为直径端点。
+    /// 半径为 0 且圆心为反演中心的圆退化为反演中心本身，没有像，返回 `None`。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///     use rust_geometry::inversion::InversionImage;
+    ///
+    ///     let rd = Round::new(Point::new(0.0, 0.0), 2.0);
+    ///     match rd.invert_round(Round::new(Point::new(1.0, 0.0), 1.0)) {
+    ///         Some(InversionImage::Line(l)) => assert_eq!(l.proj(Point::new(0.0, 0.0)), Point::new(2.0, 0.0)),
+    ///         _ => panic!("unexpected result"),
+    ///     }
+    ///     assert!(rd.invert_round(Round::new(Point::new(0.0, 0.0), 0.0)).is_none());
+    ///
+    pub fn invert_round(&self, rd: Round) -> Option<InversionImage> {
+        let v = rd.o - self.o;
+        if eq_f64(v.dis(), 0.0) {
+            if eq_f64(rd.r, 0.0) {
+                return None
+            }
+            return Some(InversionImage::Round(Round::new(self.o, self.r * self.r / rd.r)))
+        }
+
+        let u = v.normalize();
+        let far = rd.o + u * rd.r;
+        let near = rd.o - u * rd.r;
+        let far_img = self.invert_point(far).unwrap();
+        Some(match self.invert_point(near) {
+            None => InversionImage::Line(Line::new(far_img, far_img + Point::new(-u.y, u.x))),
+            Some(near_img) => InversionImage::Round(Round::new((far_img + near_img) / 2.0, (far_img - near_img).dis() / 2.0)),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 检查一组点反演后是否都落在给定的像上。
+    fn check_image(inv: &Round, pts: &[Point], img: InversionImage) {
+        for &p in pts {
+            let q = inv.invert_point(p).unwrap();
+            match img {
+                InversionImage::Line(l) => assert!(eq_f64((l.proj(q) - q).dis(), 0.0)),
+                InversionImage::Round(c) => assert!(eq_f64((q - c.o).dis(), c.r)),
+            }
+        }
+    }
+
+    #[test]
+    fn invert_test() {
+        let inv = Round::new(Point::new(1.0, -1.0), 3.0);
+        let p = Point::new(2.5, 3.0);
+        assert_eq!(inv.invert_point(inv.invert_point(p).unwrap()), Some(p));
+
+        let lines = [Line::new(Point::new(-3.0, 4.0), Point::new(5.0, 1.0)),
+                     Line::new(Point::new(1.0, -1.0), Point::new(3.0, 2.0))];
+        for l in lines {
+            let pts: Vec<Point> = [-2.0, -0.5, 0.3, 1.7, 4.0].iter().map(|&t| l.a + l.vec() * t)
+                .filter(|&q| q != inv.o).collect();
+            check_image(&inv, &pts, inv.invert_line(l));
+        }
+        assert!(matches!(inv.invert_line(lines[1]), InversionImage::Line(_)));
+
+        let rounds = [Round::new(Point::new(4.0, 2.0), 1.5), Round::new(Point::new(1.5, -0.5), 2.0),
+                      Round::new(Point::new(1.0, 1.0), 2.0), Round::new(Point::new(1.0, -1.0), 1.0)];
+        for rd in rounds {
+            let pts: Vec<Point> = [0.3, 1.1, 2.0, 3.5, 5.0].iter()
+                .map(|&t| rd.o + Point::new(rd.r, 0.0).rot(t)).collect();
+            check_image(&inv, &pts, inv.invert_round(rd).unwrap());
+        }
+        assert!(matches!(inv.invert_round(rounds[2]), Some(InversionImage::Line(_))));
+        assert!(inv.invert_round(Round::new(inv.o, 0.0)).is_none());
+    }
+}
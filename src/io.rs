@@ -0,0 +1,378 @@
+use crate::point::*;
+use crate::line::*;
+use crate::round::*;
+use crate::convex_hull::*;
+use std::io::{self, BufRead, BufWriter, StdinLock, Stdout, Write};
+use std::str::FromStr;
+
+/// `Scanner` 面向算法竞赛的带缓冲输入读取器，按空白分隔逐个读取数值并直接构造几何对象。
+///
+/// 读取时直接在底层缓冲区上切分，不逐行分配字符串，适合读入大量数据。
+/// 各方法在输入结束或无法解析时返回 `None`。
+///
+///     use rust_geometry::point::Point;
+///     use rust_geometry::io::Scanner;
+///
+///     let mut sc = Scanner::new("3\n0 0\n1 0\n0.5 2\n7".as_bytes());
+///     let pts = sc.read_points().unwrap();
+///     assert_eq!(pts[2], Point::new(0.5, 2.0));
+///     assert_eq!(sc.read::<i64>(), Some(7));
+///     assert_eq!(sc.read::<i64>(), None);
+///
+pub struct Scanner<R: BufRead> {
+    reader: R,
+    buf: Vec<u8>,
+}
+
+impl Scanner<StdinLock<'static>> {
+    /// 从标准输入读取。
+    pub fn stdin() -> Self {
+        Scanner::new(io::stdin().lock())
+    }
+}
+
+impl<R: BufRead> Scanner<R> {
+    /// 从任意带缓冲的输入源读取。
+    pub fn new(reader: R) -> Self {
+        Scanner { reader, buf: Vec::new() }
+    }
+
+    /// 读取下一个以空白分隔的词，输入结束时返回 `None`。
+    fn next_token(&mut self) -> Option<&str> {
+        self.buf.clear();
+        loop {
+            let data = self.reader.fill_buf().ok()?;
+            if data.is_empty() {
+                break
+            }
+            let mut i = 0;
+            if self.buf.is_empty() {
+                while i < data.len() && data[i].is_ascii_whitespace() {
+                    i += 1;
+                }
+            }
+            let start = i;
+            while i < data.len() && !data[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            self.buf.extend_from_slice(&data[start .. i]);
+            // 词的结尾可能跨越缓冲区边界，只有遇到空白才算读完。
+            let done = i < data.len() && !self.buf.is_empty();
+            self.reader.consume(i);
+            if done {
+                break
+            }
+        }
+        if self.buf.is_empty() {
+            return None
+        }
+        std::str::from_utf8(&self.buf).ok()
+    }
+
+    /// 读取下一个词并解析为 `T`，可用于读取整数、浮点数等。
+    pub fn read<T: FromStr>(&mut self) -> Option<T> {
+        self.next_token()?.parse().ok()
+    }
+
+    /// 读取两个数作为点的坐标。
+    pub fn read_point(&mut self) -> Option<Point> {
+        Some(Point::new(self.read()?, self.read()?))
+    }
+
+    /// 读取四个数作为直线（线段）两个端点的坐标。
+    pub fn read_line(&mut self) -> Option<Line> {
+        Some(Line::new(self.read_point()?, self.read_point()?))
+    }
+
+    /// 读取三个数作为圆的圆心坐标与半径。
+    pub fn read_round(&mut self) -> Option<Round> {
+        Some(Round::new(self.read_point()?, self.read()?))
+    }
+
+    /// 先读取点数 `n`，再读取 `n` 个点。
+    pub fn read_points(&mut self) -> Option<Vec<Point>> {
+        let n: usize = self.read()?;
+        self.read_n_points(n)
+    }
+
+    /// 读取 `n` 个点。
+    pub fn read_n_points(&mut self, n: usize) -> Option<Vec<Point>> {
+        (0 .. n).map(|_| self.read_point()).collect()
+    }
+}
+
+/// `OutputFormat` 输出几何对象时使用的格式。
+///
+/// 点输出为 `点左括号 x 坐标分隔符 y 点右括号`，直线、圆、凸包等复合对象输出为 `左括号 各部分以分隔符连接 右括号`，
+/// 其中直线的各部分为两个端点，圆的各部分为圆心与半径，凸包的各部分为逆时针顺序的顶点。
+/// 默认格式保留 5 位小数、以空格分隔且不带括号，并把舍入后为 0 的负数输出为 0，适合直接提交给评测系统。
+///
+///     use rust_geometry::point::Point;
+///     use rust_geometry::line::Line;
+///     use rust_geometry::io::{OutputFormat, FormatWith};
+///
+///     let p = Point::new(-0.000001, 2.0);
+///     assert_eq!(p.format_with(&OutputFormat::default()), "0.00000 2.00000");
+///     let f = OutputFormat::default().precision(2).coord_sep(",").point_brackets("(", ")").item_sep("-");
+///     assert_eq!(Line::new(p, Point::new(1.0, 1.0)).format_with(&f), "(0.00,2.00)-(1.00,1.00)");
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutputFormat {
+    pub precision: usize,
+    pub coord_sep: String,
+    pub item_sep: String,
+    pub point_brackets: (String, String),
+    pub shape_brackets: (String, String),
+    pub suppress_negative_zero: bool,
+}
+
+impl Default for OutputFormat {
+    fn default() -> Self {
+        OutputFormat {
+            precision: 5,
+            coord_sep: " ".to_string(),
+            item_sep: " ".to_string(),
+            point_brackets: (String::new(), String::new()),
+            shape_brackets: (String::new(), String::new()),
+            suppress_negative_zero: true,
+        }
+    }
+}
+
+impl OutputFormat {
+    /// 设置保留的小数位数。
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// 设置点的两个坐标之间的分隔符。
+    pub fn coord_sep(mut self, sep: &str) -> Self {
+        self.coord_sep = sep.to_string();
+        self
+    }
+
+    /// 设置复合对象各部分之间的分隔符。
+    pub fn item_sep(mut self, sep: &str) -> Self {
+        self.item_sep = sep.to_string();
+        self
+    }
+
+    /// 设置点两侧的括号。
+    pub fn point_brackets(mut self, open: &str, close: &str) -> Self {
+        self.point_brackets = (open.to_string(), close.to_string());
+        self
+    }
+
+    /// 设置复合对象两侧的括号。
+    pub fn shape_brackets(mut self, open: &str, close: &str) -> Self {
+        self.shape_brackets = (open.to_string(), close.to_string());
+        self
+    }
+
+    /// 设置是否把舍入后为 0 的负数（如 `-0.00000`）输出为 0。
+    pub fn suppress_negative_zero(mut self, suppress: bool) -> Self {
+        self.suppress_negative_zero = suppress;
+        self
+    }
+
+    /// 按格式输出一个浮点数，追加到 `out` 的末尾。
+    fn write_f64(&self, x: f64, out: &mut String) {
+        let s = format!("{:.*}", self.precision, x);
+        match s.strip_prefix('-') {
+            Some(t) if self.suppress_negative_zero && t.bytes().all(|c| c == b'0' || c == b'.') => out.push_str(t),
+            _ => out.push_str(&s),
+        }
+    }
+
+    /// 以复合对象的括号与分隔符输出若干部分。
+    fn write_items<T: FormatWith>(&self, items: &[&T], out: &mut String) {
+        out.push_str(&self.shape_brackets.0);
+        for (i, x) in items.iter().enumerate() {
+            if i > 0 {
+                out.push_str(&self.item_sep);
+            }
+            x.write_with(self, out);
+        }
+        out.push_str(&self.shape_brackets.1);
+    }
+}
+
+/// 可以按 `OutputFormat` 输出的对象。
+pub trait FormatWith {
+    /// 按格式 `f` 输出，追加到 `out` 的末尾。
+    fn write_with(&self, f: &OutputFormat, out: &mut String);
+
+    /// 按格式 `f` 输出为字符串。
+    fn format_with(&self, f: &OutputFormat) -> String {
+        let mut out = String::new();
+        self.write_with(f, &mut out);
+        out
+    }
+}
+
+impl FormatWith for f64 {
+    fn write_with(&self, f: &OutputFormat, out: &mut String) {
+        f.write_f64(*self, out);
+    }
+}
+
+impl FormatWith for Point {
+    fn write_with(&self, f: &OutputFormat, out: &mut String) {
+        out.push_str(&f.point_brackets.0);
+        f.write_f64(self.x, out);
+        out.push_str(&f.coord_sep);
+        f.write_f64(self.y, out);
+        out.push_str(&f.point_brackets.1);
+    }
+}
+
+impl FormatWith for Line {
+    fn write_with(&self, f: &OutputFormat, out: &mut String) {
+        f.write_items(&[&self.a, &self.b], out);
+    }
+}
+
+impl FormatWith for Round {
+    fn write_with(&self, f: &OutputFormat, out: &mut String) {
+        out.push_str(&f.shape_brackets.0);
+        self.o.write_with(f, out);
+        out.push_str(&f.item_sep);
+        f.write_f64(self.r, out);
+        out.push_str(&f.shape_brackets.1);
+    }
+}
+
+impl FormatWith for ConvexHull {
+    fn write_with(&self, f: &OutputFormat, out: &mut String) {
+        let pts = self.get_points();
+        f.write_items(&pts.iter().collect::<Vec<&Point>>(), out);
+    }
+}
+
+/// `Writer` 带缓冲的输出器，按给定的 `OutputFormat` 输出几何对象。
+///
+/// 对象先格式化到内部复用的字符串中再写入缓冲区，避免逐次分配与系统调用；`Writer` 被丢弃时会自动刷新缓冲区。
+///
+///     use rust_geometry::point::Point;
+///     use rust_geometry::round::Round;
+///     use rust_geometry::io::{OutputFormat, Writer};
+///
+///     let mut out: Vec<u8> = Vec::new();
+///     {
+///         let mut w = Writer::new(&mut out, OutputFormat::default().precision(1));
+///         w.writeln(&Round::new(Point::new(1.0, -0.01), 2.0)).unwrap();
+///         w.write(&3.14).unwrap();
+///     }
+///     assert_eq!(String::from_utf8(out).unwrap(), "1.0 0.0 2.0\n3.1");
+///
+pub struct Writer<W: Write> {
+    out: BufWriter<W>,
+    fmt: OutputFormat,
+    buf: String,
+}
+
+impl Writer<Stdout> {
+    /// 输出到标准输出。
+    pub fn stdout(fmt: OutputFormat) -> Self {
+        Writer::new(io::stdout(), fmt)
+    }
+}
+
+impl<W: Write> Writer<W> {
+    /// 输出到任意输出目标。
+    pub fn new(out: W, fmt: OutputFormat) -> Self {
+        Writer { out: BufWriter::new(out), fmt, buf: String::new() }
+    }
+
+    /// 当前使用的输出格式。
+    pub fn format(&self) -> &OutputFormat {
+        &self.fmt
+    }
+
+    /// 更换输出格式。
+    pub fn set_format(&mut self, fmt: OutputFormat) {
+        self.fmt = fmt;
+    }
+
+    /// 按格式输出一个对象，不换行。
+    pub fn write<T: FormatWith>(&mut self, x: &T) -> io::Result<()> {
+        self.buf.clear();
+        x.write_with(&self.fmt, &mut self.buf);
+        self.out.write_all(self.buf.as_bytes())
+    }
+
+    /// 按格式输出一个对象并换行。
+    pub fn writeln<T: FormatWith>(&mut self, x: &T) -> io::Result<()> {
+        self.write(x)?;
+        self.out.write_all(b"\n")
+    }
+
+    /// 原样输出一个字符串，可用于输出分隔符或整数等。
+    pub fn write_str(&mut self, s: &str) -> io::Result<()> {
+        self.out.write_all(s.as_bytes())
+    }
+
+    /// 刷新缓冲区。
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.out.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+
+    #[test]
+    fn scanner_test() {
+        let input = "  -12 3.5e1\n\n(1) 0 0 4 4\t1 1 2.5\n2 1 2\r\n3 4 x";
+        // 缓冲区容量很小，词会跨越缓冲区边界。
+        let mut sc = Scanner::new(BufReader::with_capacity(3, input.as_bytes()));
+        assert_eq!(sc.read::<i32>(), Some(-12));
+        assert_eq!(sc.read::<f64>(), Some(35.0));
+        assert_eq!(sc.read::<String>(), Some("(1)".to_string()));
+        let l = sc.read_line().unwrap();
+        assert_eq!(l.b, Point::new(4.0, 4.0));
+        let rd = sc.read_round().unwrap();
+        assert_eq!((rd.o, rd.r), (Point::new(1.0, 1.0), 2.5));
+        assert_eq!(sc.read_points(), Some(vec![Point::new(1.0, 2.0), Point::new(3.0, 4.0)]));
+        assert_eq!(sc.read::<f64>(), None);
+        assert_eq!(sc.read::<f64>(), None);
+    }
+
+    #[test]
+    fn large_test() {
+        let mut input = String::from("100000\n");
+        for i in 0 .. 100000 {
+            input.push_str(&format!("{} {}\n", i, -i));
+        }
+        let pts = Scanner::new(input.as_bytes()).read_points().unwrap();
+        assert_eq!(pts.len(), 100000);
+        assert_eq!(pts[99999], Point::new(99999.0, -99999.0));
+    }
+
+    #[test]
+    fn format_test() {
+        let f = OutputFormat::default().precision(3);
+        assert_eq!((-0.0004).format_with(&f), "0.000");
+        assert_eq!((-0.0004).format_with(&f.clone().suppress_negative_zero(false)), "-0.000");
+        assert_eq!((-0.0005001).format_with(&f), "-0.001");
+
+        let hull = ConvexHull::get_convex_hull(vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0),
+                                                    Point::new(1.0, 1.0), Point::new(0.5, 0.2)]);
+        let g = f.clone().precision(1).point_brackets("(", ")").coord_sep(",").shape_brackets("{", "}").item_sep(";");
+        assert_eq!(hull.format_with(&g), "{(0.0,0.0);(1.0,0.0);(1.0,1.0)}");
+        let rd = Round::new(Point::new(-1e-9, 2.5), 1.0);
+        assert_eq!(rd.format_with(&g), "{(0.0,2.5);1.0}");
+
+        let mut out: Vec<u8> = Vec::new();
+        let mut w = Writer::new(&mut out, g);
+        w.writeln(&Line::new(Point::new(1.0, 2.0), Point::new(3.0, 4.0))).unwrap();
+        w.set_format(f);
+        w.write(&Point::new(0.1, 0.2)).unwrap();
+        w.write_str(" 42\n").unwrap();
+        drop(w);
+        assert_eq!(String::from_utf8(out).unwrap(), "{(1.0,2.0);(3.0,4.0)}\n0.100 0.200 42\n");
+    }
+}
@@ -0,0 +1,319 @@
+use crate::*;
+use crate::point::*;
+use crate::round::*;
+use std::ops::{Add, Sub, Mul};
+
+/// 能够存入 `KdTree` 的二维点。`Point` 使用浮点坐标，`(i64, i64)` 使用精确的整数坐标。
+///
+/// 整数坐标的差与距离平方在 `i128` 中计算，为保证不溢出，坐标的绝对值须小于 2^62（约 4.6e18）。
+pub trait KdPoint: Copy {
+    /// 坐标与距离平方的类型。
+    type Scalar: Copy + PartialOrd + Add<Output = Self::Scalar> + Sub<Output = Self::Scalar> + Mul<Output = Self::Scalar>;
+
+    /// 第 `axis` 维的坐标，`axis` 为 0 或 1。
+    fn coord(&self, axis: usize) -> Self::Scalar;
+
+    /// 两点间距离的平方。
+    fn sqrdis_to(&self, p: &Self) -> Self::Scalar;
+
+    /// 检查坐标是否在距离计算不会溢出的范围内，超出时 panic。默认不做检查。
+    fn check_range(&self) {}
+}
+
+impl KdPoint for Point {
+    type Scalar = f64;
+
+    fn coord(&self, axis: usize) -> f64 {
+        if axis == 0 { self.x } else { self.y }
+    }
+
+    fn sqrdis_to(&self, p: &Self) -> f64 {
+        (*self - *p).sqrdis()
+    }
+}
+
+impl KdPoint for (i64, i64) {
+    type Scalar = i128;
+
+    fn coord(&self, axis: usize) -> i128 {
+        if axis == 0 { self.0 as i128 } else { self.1 as i128 }
+    }
+
+    fn sqrdis_to(&self, p: &Self) -> i128 {
+        let (dx, dy) = (self.0 as i128 - p.0 as i128, self.1 as i128 - p.1 as i128);
+        dx * dx + dy * dy
+    }
+
+    fn check_range(&self) {
+        const LIMIT: u64 = 1 << 62;
+        assert!(self.0.unsigned_abs() < LIMIT && self.1.unsigned_abs() < LIMIT,
+                "coordinates of (i64, i64) points must be less than 2^62 in absolute value");
+    }
+}
+
+/// `KdTree` 静态二维树，建树后支持 k 近邻、圆形范围与轴对齐矩形范围查询，查询结果均为点在输入中的下标。
+///
+/// 树以隐式平衡二叉树的形式存放在数组中，每个区间的中点为子树根，按坐标跨度较大的一维划分。
+pub struct KdTree<P: KdPoint> {
+    pts: Vec<(P, usize)>,
+    axis: Vec<usize>,
+}
+
+impl<P: KdPoint> KdTree<P> {
+    /// 根据点集建树，时间复杂度 O(n log n)。
+    ///
+    /// 对 `(i64, i64)`，存在坐标绝对值不小于 2^62 的点时 panic，查询点同样须满足该范围，否则查询时 panic。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::kd_tree::KdTree;
+    ///
+    ///     let pts = vec![Point::new(0.0, 0.0), Point::new(2.0, 1.0), Point::new(5.0, 5.0)];
+    ///     let tree = KdTree::new(&pts);
+    ///     assert_eq!(tree.nearest(Point::new(1.8, 1.5)).map(|x| x.0), Some(1));
+    ///
+    pub fn new(pts: &[P]) -> Self {
+        pts.iter().for_each(P::check_range);
+        let mut tree = KdTree {
+            pts: pts.iter().copied().zip(0 ..).collect(),
+            axis: vec![0; pts.len()],
+        };
+        tree.build(0, pts.len());
+        tree
+    }
+
+    /// 点的个数。
+    pub fn len(&self) -> usize {
+        self.pts.len()
+    }
+
+    /// 树是否为空。
+    pub fn is_empty(&self) -> bool {
+        self.pts.is_empty()
+    }
+
+    fn build(&mut self, l: usize, r: usize) {
+        if r - l <= 1 {
+            return
+        }
+        let spread = |axis: usize| {
+            let mut lo = self.pts[l].0.coord(axis);
+            let mut hi = lo;
+            for (p, _) in &self.pts[l + 1 .. r] {
+                let c = p.coord(axis);
+                if c < lo { lo = c; }
+                if c > hi { hi = c; }
+            }
+            hi - lo
+        };
+        let axis = if spread(0) >= spread(1) { 0 } else { 1 };
+
+        let mid = (l + r) / 2;
+        self.pts[l .. r].select_nth_unstable_by(mid - l, |a, b| {
+            a.0.coord(axis).partial_cmp(&b.0.coord(axis)).unwrap_or(std::cmp::Ordering::Equal)
+        });
+        self.axis[mid] = axis;
+        self.build(l, mid);
+        self.build(mid + 1, r);
+    }
+
+    /// 查询距离 `q` 最近的点，返回其下标与距离的平方，树为空时返回 `None`。
+    pub fn nearest(&self, q: P) -> Option<(usize, P::Scalar)> {
+        self.k_nearest(q, 1).pop()
+    }
+
+    /// 查询距离 `q` 最近的 `k` 个点，返回下标与距离的平方，按距离从小到大排列。
+    ///
+    ///     use rust_geometry::kd_tree::KdTree;
+    ///
+    ///     let pts: Vec<(i64, i64)> = vec![(0, 0), (3, 4), (1, 1), (-2, 0)];
+    ///     let tree = KdTree::new(&pts);
+    ///     assert_eq!(tree.k_nearest((0, 0), 3), vec![(0, 0), (2, 2), (3, 4)]);
+    ///
+    pub fn k_nearest(&self, q: P, k: usize) -> Vec<(usize, P::Scalar)> {
+        q.check_range();
+        let mut res = Vec::with_capacity(k + 1);
+        if k > 0 {
+            self.k_nearest_rec(0, self.len(), &q, k, &mut res);
+        }
+        res
+    }
+
+    fn k_nearest_rec(&self, l: usize, r: usize, q: &P, k: usize, res: &mut Vec<(usize, P::Scalar)>) {
+        if l >= r {
+            return
+        }
+        let mid = (l + r) / 2;
+        let (p, id) = self.pts[mid];
+        let d = p.sqrdis_to(q);
+        if res.len() < k || d < res[res.len() - 1].1 {
+            let pos = res.iter().position(|x| d < x.1).unwrap_or(res.len());
+            res.insert(pos, (id, d));
+            res.truncate(k);
+        }
+
+        let axis = self.axis[mid];
+        let diff = q.coord(axis) - p.coord(axis);
+        let (near, far) = if q.coord(axis) < p.coord(axis) {
+            ((l, mid), (mid + 1, r))
+        }
+        else {
+            ((mid + 1, r), (l, mid))
+        };
+        self.k_nearest_rec(near.0, near.1, q, k, res);
+        if res.len() < k || diff * diff < res[res.len() - 1].1 {
+            self.k_nearest_rec(far.0, far.1, q, k, res);
+        }
+    }
+
+    /// 查询与 `o` 距离的平方不超过 `r2` 的所有点。
+    pub fn within(&self, o: P, r2: P::Scalar) -> Vec<usize> {
+        o.check_range();
+        let mut res = Vec::new();
+        self.within_rec(0, self.len(), &o, r2, &mut res);
+        res
+    }
+
+    fn within_rec(&self, l: usize, r: usize, o: &P, r2: P::Scalar, res: &mut Vec<usize>) {
+        if l >= r {
+            return
+        }
+        let mid = (l + r) / 2;
+        let (p, id) = self.pts[mid];
+        if p.sqrdis_to(o) <= r2 {
+            res.push(id);
+        }
+
+        let axis = self.axis[mid];
+        let diff = o.coord(axis) - p.coord(axis);
+        if o.coord(axis) <= p.coord(axis) || diff * diff <= r2 {
+            self.within_rec(l, mid, o, r2, res);
+        }
+        if o.coord(axis) >= p.coord(axis) || diff * diff <= r2 {
+            self.within_rec(mid + 1, r, o, r2, res);
+        }
+    }
+
+    /// 查询位于轴对齐矩形 `[lo.x, hi.x] × [lo.y, hi.y]` 内（含边界）的所有点。
+    ///
+    ///     use rust_geometry::kd_tree::KdTree;
+    ///
+    ///     let pts: Vec<(i64, i64)> = vec![(0, 0), (3, 4), (1, 1), (-2, 0)];
+    ///     let tree = KdTree::new(&pts);
+    ///     let mut v = tree.in_rect((0, 0), (3, 3));
+    ///     v.sort();
+    ///     assert_eq!(v, vec![0, 2]);
+    ///
+    pub fn in_rect(&self, lo: P, hi: P) -> Vec<usize> {
+        let mut res = Vec::new();
+        self.in_rect_rec(0, self.len(), &lo, &hi, &mut res);
+        res
+    }
+
+    fn in_rect_rec(&self, l: usize, r: usize, lo: &P, hi: &P, res: &mut Vec<usize>) {
+        if l >= r {
+            return
+        }
+        let mid = (l + r) / 2;
+        let (p, id) = self.pts[mid];
+        if (0 .. 2).all(|a| lo.coord(a) <= p.coord(a) && p.coord(a) <= hi.coord(a)) {
+            res.push(id);
+        }
+
+        let axis = self.axis[mid];
+        if lo.coord(axis) <= p.coord(axis) {
+            self.in_rect_rec(l, mid, lo, hi, res);
+        }
+        if hi.coord(axis) >= p.coord(axis) {
+            self.in_rect_rec(mid + 1, r, lo, hi, res);
+        }
+    }
+}
+
+impl KdTree<Point> {
+    /// 查询位于圆 `rd` 内（含边界，基于 `EPS`）的所有点。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///     use rust_geometry::kd_tree::KdTree;
+    ///
+    ///     let pts = vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(2.0, 2.0)];
+    ///     let tree = KdTree::new(&pts);
+    ///     let mut v = tree.in_round(Round::new(Point::new(0.0, 0.0), 1.0));
+    ///     v.sort();
+    ///     assert_eq!(v, vec![0, 1]);
+    ///
+    pub fn in_round(&self, rd: Round) -> Vec<usize> {
+        self.within(rd.o, (rd.r + EPS) * (rd.r + EPS))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Lcg;
+
+    fn pseudo_random(n: usize, seed: u64) -> Vec<(i64, i64)> {
+        let mut rng = Lcg::new(seed);
+        (0 .. n).map(|_| (rng.below(200) as i64 - 100, rng.below(200) as i64 - 100)).collect()
+    }
+
+    #[test]
+    fn k_nearest_test() {
+        let pts = pseudo_random(400, 11);
+        let fpts: Vec<Point> = pts.iter().map(|&(x, y)| Point::new(x as f64, y as f64)).collect();
+        let tree = KdTree::new(&pts);
+        let ftree = KdTree::new(&fpts);
+        for q in pseudo_random(50, 12) {
+            let mut brute: Vec<i128> = pts.iter().map(|p| p.sqrdis_to(&q)).collect();
+            brute.sort();
+            let res = tree.k_nearest(q, 5);
+            let d: Vec<i128> = res.iter().map(|x| x.1).collect();
+            assert_eq!(d, brute[.. 5].to_vec());
+            for (id, d) in res {
+                assert_eq!(pts[id].sqrdis_to(&q), d);
+            }
+            let (_, fd) = ftree.nearest(Point::new(q.0 as f64, q.1 as f64)).unwrap();
+            assert!(eq_f64(fd, brute[0] as f64));
+        }
+    }
+
+    #[test]
+    fn large_coord_test() {
+        // 坐标约 3e9 时距离平方超出 i64 的范围。
+        let pts = vec![(3_000_000_000, -3_000_000_000), (-3_000_000_000, 3_000_000_000), (1, 2)];
+        let tree = KdTree::new(&pts);
+        assert_eq!(tree.nearest((2_900_000_000, -2_900_000_000)), Some((0, 2 * 100_000_000i128 * 100_000_000)));
+        let far = pts[0].sqrdis_to(&pts[1]);
+        assert_eq!(far, 72_000_000_000_000_000_000);
+        assert_eq!(tree.k_nearest((0, 0), 1), vec![(2, 5)]);
+
+        let limit = 1i64 << 62;
+        assert!(std::panic::catch_unwind(|| KdTree::new(&[(0, limit)])).is_err());
+        assert!(std::panic::catch_unwind(|| tree.nearest((-limit, 0))).is_err());
+        assert!(std::panic::catch_unwind(|| tree.within((i64::MIN, 0), 1)).is_err());
+    }
+
+    #[test]
+    fn range_test() {
+        let pts = pseudo_random(400, 21);
+        let fpts: Vec<Point> = pts.iter().map(|&(x, y)| Point::new(x as f64, y as f64)).collect();
+        let tree = KdTree::new(&pts);
+        let ftree = KdTree::new(&fpts);
+
+        let mut v = tree.in_rect((-30, -10), (20, 50));
+        v.sort();
+        let brute: Vec<usize> = (0 .. pts.len())
+            .filter(|&i| -30 <= pts[i].0 && pts[i].0 <= 20 && -10 <= pts[i].1 && pts[i].1 <= 50)
+            .collect();
+        assert_eq!(v, brute);
+
+        let mut v = ftree.in_round(Round::new(Point::new(3.0, -4.0), 25.0));
+        v.sort();
+        let brute: Vec<usize> = (0 .. pts.len()).filter(|&i| pts[i].sqrdis_to(&(3, -4)) <= 625).collect();
+        assert_eq!(v, brute);
+
+        let empty: KdTree<Point> = KdTree::new(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.nearest(Point::new(0.0, 0.0)), None);
+    }
+}
@@ -7,12 +7,15 @@
 /// 浮点比较误差常数
 pub const EPS: f64 = 1e-9;
 
+/// 下标数组中表示空节点或不存在的元素的占位下标。
+pub(crate) const NIL: usize = usize::MAX;
+
 /// 浮点比较函数，当两数之差绝对值不超过 `EPS` 时则认为它们相等，用以防止浮点型精度误差。
 pub fn eq_f64(a: f64, b: f64) -> bool {
     (a - b).abs() < EPS
 }
 
-/// 该模块定义了计算几何最基本的类型 `Point`，并实现了它的许多必要的trait。
+/// 该模块定义了计算几何最基本的类型 `Point`，并实现了它的许多必要的trait，以及平面最近点对算法。
 pub mod point;
 
 /// 该模块定义了表示线段与直线的类型 `Line`，实现了它与点、直线交互的若干功能。
@@ -21,5 +24,57 @@ pub mod line;
 /// 该模块定义了凸壳类型 `ConvexHull`，通过维护上下凸壳的方式实现了凸壳的基本功能，并实现了通过点集构造凸壳的高效算法。
 pub mod convex_hull;
 
-/// 该模块定义了表示圆的类型 `Round`，实现了点、直线、圆交互的若干功能，并实现了对三角形的内心、外心求解。
-pub mod round;
\ No newline at end of file
+/// 该模块定义了表示圆的类型 `Round`，实现了点、直线、圆交互的若干功能，包括两圆的公切线，圆与圆、多边形、凸包的交的面积，并实现了对三角形的内心、外心求解，以及圆的并的面积与周长、固定半径的圆最多覆盖点数的求解。
+pub mod round;
+
+/// 该模块实现了 Delaunay 三角剖分的分治算法，并基于它在 O(n log n) 时间内求解平面点集的欧几里得最小生成树。
+pub mod delaunay;
+
+/// 该模块定义了静态二维树 `KdTree`，支持对点集的 k 近邻、圆形范围和矩形范围查询，并支持整数坐标下的精确计算。
+pub mod kd_tree;
+
+/// 该模块定义了轴对齐矩形类型 `Aabb`，实现了各类图形的包围盒计算、矩形的包含与相交判断，以及矩形并的面积与周长的扫描线算法。
+pub mod aabb;
+
+/// 该模块实现了线段集合上的扫描线算法，包括求出所有线段交点的 Bentley-Ottmann 算法，以及判断是否存在相交线段的 Shamos-Hoey 算法。
+pub mod sweep;
+
+/// 该模块定义了圆弧类型 `Arc`，实现了弧长、扇形与弓形面积的计算，以及圆弧与直线、线段、圆、圆弧的求交。
+pub mod arc;
+
+/// 该模块定义了三角形类型 `Triangle`，实现了面积、角度、重心坐标等基本度量，以及内切圆、外接圆、垂心、九点圆、旁切圆、费马点等经典问题的求解。
+pub mod triangle;
+
+/// 该模块实现了关于圆的反演变换，可以求出点、直线、圆在反演下的像。
+pub mod inversion;
+
+/// 该模块实现了满足相切条件的圆的构造，包括一般的阿波罗尼奥斯问题以及竞赛中常见的给定半径的情形。
+pub mod apollonius;
+
+/// 该模块定义了椭圆类型 `Ellipse`，实现了面积、周长近似、点的包含判断，以及椭圆与直线、圆的求交和圆在仿射变换下的像。
+pub mod ellipse;
+
+/// 该模块定义了平面仿射变换 `Transform2D` 及 `Transformable` 特征，支持平移、旋转、缩放、错切、轴对称及其复合与求逆，并可统一作用于各类图形。
+pub mod transform;
+
+/// 该模块定义了三维空间中的点 `Point3`、直线 `Line3`、平面 `Plane` 与球 `Sphere`，实现了投影、距离、求交以及异面直线的公垂线等计算。
+pub mod space;
+
+/// 该模块定义了三维凸包类型 `ConvexHull3`，使用增量法构造由有向三角形面组成的凸包，并实现了体积、表面积与点的包含判断。
+pub mod convex_hull3;
+
+/// 该模块实现了单位球面上的球面几何计算，包括经纬度与三维单位向量的互相转换、大圆距离、球面三角形面积以及大圆的求交。
+pub mod spherical;
+
+/// 该模块为 `Point`、`Line`、`Round` 实现了 `FromStr`，可以解析它们 `Display` 输出的格式以及以空白分隔的数值，解析失败时返回 `ParseGeometryError`。
+pub mod parse;
+
+/// 该模块提供了面向算法竞赛的带缓冲输入读取器 `Scanner` 与输出器 `Writer`，可以快速读取数值并直接构造点、直线、圆与点集，并按可配置的格式输出几何对象。
+pub mod io;
+
+/// 该模块提供了用于调试的 SVG 场景 `Scene`，可以绘制带颜色与标签的点、线段、圆与凸包，并自动适配视口、绘制坐标轴与网格。
+pub mod svg;
+
+/// 测试共用的辅助工具。
+#[cfg(test)]
+mod test_util;
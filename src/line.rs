@@ -1,8 +1,9 @@
-use crate::eq_f64;
+use crate::*;
 use crate::point::*;
 use std::fmt;
 
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line{
     pub a: Point,
     pub b: Point,
@@ -86,6 +87,70 @@ impl Line {
             Some(self.a + self.vec() * (s1 / (s1 + s2)))
         }
     }
+
+    /// 判断点是否在线段上（含端点，基于 `EPS`）。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::line::Line;
+    ///
+    ///     let l = Line::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+    ///     assert!(l.on_seg(Point::new(1.0, 1.0)));
+    ///     assert!(!l.on_seg(Point::new(3.0, 3.0)));
+    ///
+    pub fn on_seg(&self, p: Point) -> bool {
+        eq_f64((self.a - p) ^ (self.b - p), 0.0) && (self.a - p) * (self.b - p) < EPS
+    }
+
+    /// 判断两条线段是否有公共点（含端点接触与共线重叠）。
+    pub fn seg_intersect(&self, l: Line) -> bool {
+        let sgn = |v: f64| if v > EPS { 1 } else if v < -EPS { -1 } else { 0 };
+        let d1 = sgn(self.vec() ^ (l.a - self.a));
+        let d2 = sgn(self.vec() ^ (l.b - self.a));
+        let d3 = sgn(l.vec() ^ (self.a - l.a));
+        let d4 = sgn(l.vec() ^ (self.b - l.a));
+        if d1 * d2 < 0 && d3 * d4 < 0 {
+            return true
+        }
+        self.on_seg(l.a) || self.on_seg(l.b) || l.on_seg(self.a) || l.on_seg(self.b)
+    }
+
+    /// 计算两条线段的交点。只有当两线段恰有一个公共点时返回该点，不相交或共线重叠成一段时返回 `None`。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::line::Line;
+    ///
+    ///     let la = Line::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0));
+    ///     let lb = Line::new(Point::new(2.0, 0.0), Point::new(0.0, 2.0));
+    ///     assert_eq!(la.seg_inter(lb), Some(Point::new(1.0, 1.0)));
+    ///
+    ///     let lc = Line::new(Point::new(2.0, 2.0), Point::new(3.0, 3.0));
+    ///     assert_eq!(la.seg_inter(lc), Some(Point::new(2.0, 2.0)));
+    ///
+    ///     let ld = Line::new(Point::new(1.0, 1.0), Point::new(3.0, 3.0));
+    ///     assert_eq!(la.seg_inter(ld), None);
+    ///
+    pub fn seg_inter(&self, l: Line) -> Option<Point> {
+        if !self.seg_intersect(l) {
+            return None
+        }
+        if let Some(p) = self.inter(l) {
+            return Some(p)
+        }
+
+        // 平行且有公共点：退化为点的线段直接返回该点，否则检查共线部分是否只有一个点。
+        if !self.valid() {
+            return Some(self.a)
+        }
+        if !l.valid() {
+            return Some(l.a)
+        }
+        let d = self.vec();
+        let t = |p: Point| (p - self.a) * d;
+        let (lo, hi) = if t(l.a) < t(l.b) { (l.a, l.b) } else { (l.b, l.a) };
+        let lo = if t(lo) > 0.0 { lo } else { self.a };
+        let hi = if t(hi) < t(self.b) { hi } else { self.b };
+        if lo == hi { Some(lo) } else { None }
+    }
 }
 
 #[cfg(test)]
@@ -108,4 +173,30 @@ mod tests {
         
         assert!(eq_f64(d, 2.0_f64.sqrt()))
     }
+
+    #[test]
+    fn seg_test() {
+        let la = Line::new(Point::new(0.0, 0.0), Point::new(2.0, 0.0));
+        let lb = Line::new(Point::new(1.0, 1.0), Point::new(1.0, -1.0));
+        let lc = Line::new(Point::new(3.0, 1.0), Point::new(3.0, -1.0));
+        let ld = Line::new(Point::new(2.0, 0.0), Point::new(5.0, 0.0));
+        let pt = Line::new(Point::new(1.0, 0.0), Point::new(1.0, 0.0));
+
+        assert_eq!(la.seg_inter(lb), Some(Point::new(1.0, 0.0)));
+        assert_eq!(la.seg_inter(lc), None);
+        assert!(!la.seg_intersect(lc));
+        assert_eq!(la.seg_inter(ld), Some(Point::new(2.0, 0.0)));
+        assert_eq!(la.seg_inter(pt), Some(Point::new(1.0, 0.0)));
+        assert_eq!(pt.seg_inter(la), Some(Point::new(1.0, 0.0)));
+        assert!(la.seg_intersect(Line::new(Point::new(1.0, 0.0), Point::new(4.0, 0.0))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_test() {
+        let l: Line = serde_json::from_str(r#"{"a":{"x":0.0,"y":0.0},"b":{"x":1.0,"y":2.0}}"#).unwrap();
+        assert_eq!(l.a, Point::new(0.0, 0.0));
+        assert_eq!(l.b, Point::new(1.0, 2.0));
+        assert_eq!(serde_json::to_string(&l).unwrap(), r#"{"a":{"x":0.0,"y":0.0},"b":{"x":1.0,"y":2.0}}"#);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,158 @@
+use crate::point::*;
+use crate::line::*;
+use crate::round::*;
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// 从字符串解析几何对象时产生的错误。
+#[derive(Debug, Clone, PartialEq)]
+pub enum ParseGeometryError {
+    /// 某一项无法解析为浮点数，附带该项的原文。
+    InvalidNumber(String),
+    /// 数值的个数不符合要求。
+    WrongCount { expected: usize, found: usize },
+    /// 括号或分隔符与格式不符，附带出错的输入。
+    Malformed(String),
+}
+
+impl fmt::Display for ParseGeometryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseGeometryError::InvalidNumber(s) => write!(f, "invalid number `{}`", s),
+            ParseGeometryError::WrongCount { expected, found } => write!(f, "expected {} numbers, found {}", expected, found),
+            ParseGeometryError::Malformed(s) => write!(f, "malformed input `{}`", s),
+        }
+    }
+}
+
+impl Error for ParseGeometryError {}
+
+/// 解析一个浮点数，忽略两侧的空白。
+fn parse_number(s: &str) -> Result<f64, ParseGeometryError> {
+    let s = s.trim();
+    s.parse::<f64>().map_err(|_| ParseGeometryError::InvalidNumber(s.to_string()))
+}
+
+/// 解析恰好 `n` 个以空白分隔的浮点数。
+fn parse_numbers(s: &str, n: usize) -> Result<Vec<f64>, ParseGeometryError> {
+    let v = s.split_whitespace().map(parse_number).collect::<Result<Vec<f64>, _>>()?;
+    if v.len() != n {
+        return Err(ParseGeometryError::WrongCount { expected: n, found: v.len() })
+    }
+    Ok(v)
+}
+
+/// 去掉字符串两侧成对的括号 `open`、`close`，不成对时返回 `Malformed`。
+fn strip_brackets(s: &str, open: char, close: char) -> Result<&str, ParseGeometryError> {
+    s.strip_prefix(open).and_then(|t| t.strip_suffix(close))
+        .ok_or_else(|| ParseGeometryError::Malformed(s.to_string()))
+}
+
+impl FromStr for Point {
+    type Err = ParseGeometryError;
+
+    /// 支持解析 `Display` 输出的 `(x,y)` 格式，以及以空白分隔的两个数。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///
+    ///     assert_eq!("(1.50000,-2.00000)".parse::<Point>(), Ok(Point::new(1.5, -2.0)));
+    ///     assert_eq!("1.5 -2".parse::<Point>(), Ok(Point::new(1.5, -2.0)));
+    ///     assert!("(1.5;-2)".parse::<Point>().is_err());
+    ///
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if !s.starts_with('(') {
+            let v = parse_numbers(s, 2)?;
+            return Ok(Point::new(v[0], v[1]))
+        }
+        let parts: Vec<&str> = strip_brackets(s, '(', ')')?.split(',').collect();
+        if parts.len() != 2 {
+            return Err(ParseGeometryError::WrongCount { expected: 2, found: parts.len() })
+        }
+        Ok(Point::new(parse_number(parts[0])?, parse_number(parts[1])?))
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseGeometryError;
+
+    /// 支持解析 `Display` 输出的 `(x1,y1)-(x2,y2)` 格式，以及以空白分隔的四个数。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::line::Line;
+    ///
+    ///     let l: Line = "(0.00000,-1.00000)-(-2.00000,3.00000)".parse().unwrap();
+    ///     assert_eq!(l.a, Point::new(0.0, -1.0));
+    ///     assert_eq!(l.b, Point::new(-2.0, 3.0));
+    ///
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if !s.starts_with('(') {
+            let v = parse_numbers(s, 4)?;
+            return Ok(Line::new(Point::new(v[0], v[1]), Point::new(v[2], v[3])))
+        }
+        // 坐标中可能出现负号，因此以两点之间的 `)-(` 作为分隔。
+        let mid = s.find(")-(").ok_or_else(|| ParseGeometryError::Malformed(s.to_string()))?;
+        Ok(Line::new(s[.. mid + 1].parse()?, s[mid + 2 ..].parse()?))
+    }
+}
+
+impl FromStr for Round {
+    type Err = ParseGeometryError;
+
+    /// 支持解析 `Display` 输出的 `[(x,y) r]` 格式，以及以空白分隔的三个数（圆心坐标与半径）。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///
+    ///     let rd: Round = "[(1.00000,2.00000) 0.5]".parse().unwrap();
+    ///     assert_eq!(rd.o, Point::new(1.0, 2.0));
+    ///     assert_eq!(rd.r, 0.5);
+    ///     assert_eq!("1 2 0.5".parse::<Round>().unwrap().r, 0.5);
+    ///
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if !s.starts_with('[') {
+            let v = parse_numbers(s, 3)?;
+            return Ok(Round::new(Point::new(v[0], v[1]), v[2]))
+        }
+        let inner = strip_brackets(s, '[', ']')?;
+        let end = inner.rfind(')').ok_or_else(|| ParseGeometryError::Malformed(s.to_string()))?;
+        Ok(Round::new(inner[.. end + 1].parse()?, parse_number(&inner[end + 1 ..])?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_test() {
+        let p = Point::new(-1.25, 3.5);
+        assert_eq!(p.to_string().parse::<Point>(), Ok(p));
+        assert_eq!(" ( -1.25 , 3.5 ) ".parse::<Point>(), Ok(p));
+
+        let l = Line::new(Point::new(-1.0, -2.0), Point::new(-3.0, 4.5));
+        let back: Line = l.to_string().parse().unwrap();
+        assert_eq!((back.a, back.b), (l.a, l.b));
+        let back: Line = "-1 -2\t-3 4.5".parse().unwrap();
+        assert_eq!((back.a, back.b), (l.a, l.b));
+
+        let rd = Round::new(Point::new(0.5, -0.5), 2.25);
+        let back: Round = rd.to_string().parse().unwrap();
+        assert_eq!(back.o, rd.o);
+        assert_eq!(back.r, rd.r);
+    }
+
+    #[test]
+    fn error_test() {
+        assert_eq!("1 x".parse::<Point>(), Err(ParseGeometryError::InvalidNumber("x".to_string())));
+        assert_eq!("1 2 3".parse::<Point>(), Err(ParseGeometryError::WrongCount { expected: 2, found: 3 }));
+        assert_eq!("(1,2,3)".parse::<Point>(), Err(ParseGeometryError::WrongCount { expected: 2, found: 3 }));
+        assert_eq!("(1,2".parse::<Point>(), Err(ParseGeometryError::Malformed("(1,2".to_string())));
+        assert!(matches!("(1,2)+(3,4)".parse::<Line>(), Err(ParseGeometryError::Malformed(_))));
+        assert!(matches!("[(1,2) r]".parse::<Round>(), Err(ParseGeometryError::InvalidNumber(_))));
+        assert_eq!(ParseGeometryError::WrongCount { expected: 4, found: 1 }.to_string(), "expected 4 numbers, found 1");
+    }
+}
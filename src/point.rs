@@ -1,10 +1,12 @@
 use crate::*;
+use crate::convex_hull::ConvexHull;
 use std::fmt;
 use std::cmp::PartialEq;
 use std::ops::{Add, Sub, Mul, Div, Neg, BitXor};
 
 /// `Point` 计算几何最基本的数据类型，既能表示点也能表示向量。支持向量的加法减法，向量与常数的乘法除法，向量的叉乘点乘（运算符号分别为 `^` 和 `*`）等基础运算。
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Point {
     pub x: f64,
     pub y: f64,
@@ -159,11 +161,112 @@ impl Point {
     pub fn rad(&self, p: Point) -> f64 {
         (*self ^ p).atan2(*self * p)
     }
+
+    /// 计算向量的极角，即 x 轴正方向逆时针旋转多少度能与该向量方向重合。返回角度为弧度制，在 (-pi, pi] 之间。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///
+    ///     assert_eq!(Point::new(0.0, 2.0).polar_angle(), std::f64::consts::FRAC_PI_2);
+    ///     assert_eq!(Point::new(-1.0, 0.0).polar_angle(), std::f64::consts::PI);
+    ///     assert_eq!(Point::new(-1.0, -0.0).polar_angle(), std::f64::consts::PI);
+    ///
+    pub fn polar_angle(&self) -> f64 {
+        // atan2 对负零可能返回 -pi，统一为 pi。
+        let t = Point::new(1.0, 0.0).rad(*self);
+        if t == -std::f64::consts::PI { std::f64::consts::PI } else { t }
+    }
+}
+
+/// 计算点集中距离最近的两个点，返回它们在输入中的下标及距离，点数少于两个时返回 `None`。
+///
+/// 先按 `ConvexHull::pt_cmp` 排序再分治，合并时按纵坐标归并并只检查分割线附近的点，时间复杂度 O(n log n)。
+///
+///     use rust_geometry::eq_f64;
+///     use rust_geometry::point::{Point, closest_pair};
+///
+///     let pts = vec![Point::new(0.0, 0.0), Point::new(5.0, 5.0), Point::new(3.0, 4.0), Point::new(9.0, 0.0)];
+///     let (i, j, d) = closest_pair(&pts).unwrap();
+///     assert_eq!((i.min(j), i.max(j)), (1, 2));
+///     assert!(eq_f64(d, 5.0_f64.sqrt()));
+///
+pub fn closest_pair(pts: &[Point]) -> Option<(usize, usize, f64)> {
+    let n = pts.len();
+    if n < 2 {
+        return None
+    }
+
+    let mut ids: Vec<usize> = (0 .. n).collect();
+    ids.sort_by(|&i, &j| ConvexHull::pt_cmp(&pts[i], &pts[j]));
+    let mut buf = vec![0; n];
+    let mut best = (ids[0], ids[1], (pts[ids[0]] - pts[ids[1]]).sqrdis());
+    closest_pair_rec(pts, &mut ids, &mut buf, &mut best);
+    Some((best.0, best.1, best.2.sqrt()))
+}
+
+/// 最近点对的分治过程，`best` 中记录当前答案及其距离的平方，返回时 `ids` 按纵坐标有序。
+fn closest_pair_rec(pts: &[Point], ids: &mut [usize], buf: &mut [usize], best: &mut (usize, usize, f64)) {
+    let n = ids.len();
+    let mut update = |i: usize, j: usize| {
+        let d = (pts[i] - pts[j]).sqrdis();
+        if d < best.2 {
+            *best = (i, j, d);
+        }
+    };
+    if n <= 3 {
+        for i in 0 .. n {
+            for j in i + 1 .. n {
+                update(ids[i], ids[j]);
+            }
+        }
+        ids.sort_by(|&i, &j| pts[i].y.total_cmp(&pts[j].y));
+        return
+    }
+
+    let mid = n / 2;
+    let mid_x = pts[ids[mid]].x;
+    closest_pair_rec(pts, &mut ids[.. mid], buf, best);
+    closest_pair_rec(pts, &mut ids[mid ..], buf, best);
+
+    let (mut i, mut j, mut k) = (0, mid, 0);
+    while i < mid || j < n {
+        if j == n || (i < mid && pts[ids[i]].y <= pts[ids[j]].y) {
+            buf[k] = ids[i];
+            i += 1;
+        }
+        else {
+            buf[k] = ids[j];
+            j += 1;
+        }
+        k += 1;
+    }
+    ids.copy_from_slice(&buf[.. n]);
+
+    let mut cnt = 0;
+    for &i in ids.iter() {
+        let p = pts[i];
+        let dx = p.x - mid_x;
+        if dx * dx >= best.2 {
+            continue
+        }
+        for &j in buf[.. cnt].iter().rev() {
+            let dy = p.y - pts[j].y;
+            if dy * dy >= best.2 {
+                break
+            }
+            let d = (p - pts[j]).sqrdis();
+            if d < best.2 {
+                *best = (j, i, d);
+            }
+        }
+        buf[cnt] = i;
+        cnt += 1;
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::Lcg;
     use std::f64::consts::PI;
 
     #[test]
@@ -217,4 +320,32 @@ mod tests {
         let theta = PI / 3.0;
         assert!(eq_f64(pa.rad(pb), theta));
     }
+
+    #[test]
+    fn closest_pair_test() {
+        let mut rng = Lcg::new(7);
+        let mut next = || rng.below(100000) as f64 / 7.0;
+        let pts: Vec<Point> = (0 .. 500).map(|_| Point::new(next(), next())).collect();
+        let mut ans = f64::INFINITY;
+        for i in 0 .. pts.len() {
+            for j in i + 1 .. pts.len() {
+                ans = ans.min((pts[i] - pts[j]).dis());
+            }
+        }
+        let (i, j, d) = closest_pair(&pts).unwrap();
+        assert!(i != j);
+        assert!(eq_f64(d, ans));
+        assert!(eq_f64((pts[i] - pts[j]).dis(), ans));
+
+        assert_eq!(closest_pair(&[Point::new(1.0, 1.0)]), None);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_test() {
+        let p = Point::new(1.0, -2.5);
+        let s = serde_json::to_string(&p).unwrap();
+        assert_eq!(s, r#"{"x":1.0,"y":-2.5}"#);
+        assert_eq!(serde_json::from_str::<Point>(&s).unwrap(), p);
+    }
 }
@@ -1,9 +1,11 @@
 use crate::*;
 use crate::point::*;
 use crate::line::*;
+use crate::convex_hull::*;
 use std::fmt;
 
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Round {
     pub o: Point,
     pub r: f64,
@@ -15,32 +17,79 @@ impl fmt::Display for Round {
     }
 }
 
+/// 圆与直线、圆与圆求交的结果。
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Intersection {
+    /// 没有公共点，且互不包含（圆与直线相离，或两圆外离）。
+    Disjoint,
+    /// 一个圆严格位于另一个圆内部，没有公共点。
+    Contained,
+    /// 两圆重合，有无穷多个公共点。
+    Coincident,
+    /// 恰有一个公共点（相切）。
+    Tangent(Point),
+    /// 恰有两个公共点。
+    Secant(Point, Point),
+}
+
+impl Intersection {
+    /// 以 `Vec` 的形式返回所有交点，重合或没有交点时为空。
+    pub fn points(&self) -> Vec<Point> {
+        match *self {
+            Intersection::Tangent(p) => vec![p],
+            Intersection::Secant(p, q) => vec![p, q],
+            _ => Vec::new(),
+        }
+    }
+}
+
 impl Round {
     /// 通过圆心和半径构造圆对象。
     pub fn new(o: Point, r: f64) -> Self {
         Round { o, r }
     }
 
-    /// 计算圆与直线的交点，若直线与圆相离则返回 `None`。
-    pub fn inter_line(&self, l: Line) -> Option<(Point, Point)> {
+    /// 计算圆与直线的交点。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::line::Line;
+    ///     use rust_geometry::round::{Round, Intersection};
+    ///
+    ///     let rd = Round::new(Point::new(0.0, 0.0), 1.0);
+    ///     let l = Line::new(Point::new(-2.0, 1.0), Point::new(2.0, 1.0));
+    ///     assert_eq!(rd.inter_line(l), Intersection::Tangent(Point::new(0.0, 1.0)));
+    ///
+    pub fn inter_line(&self, l: Line) -> Intersection {
         let proj = l.proj(self.o);
         let dis = (self.o - proj).dis();
         if eq_f64(dis, self.r) {
-            return Some((proj, proj))
+            return Intersection::Tangent(proj)
         }
         else if dis > self.r {
-            return None
+            return Intersection::Disjoint
         }
 
         let delt = (self.r * self.r - dis * dis).sqrt();
         let delt_v = l.vec().normalize();
-        Some((proj + delt_v * delt, proj - delt_v * delt))
+        Intersection::Secant(proj + delt_v * delt, proj - delt_v * delt)
     }
 
-    /// 计算圆与圆的交点，如果两圆包含或相离则返回 `None`。
-    pub fn inter_round(&self, rd: Round) -> Option<(Point, Point)> {
+    /// 计算圆与圆的交点，区分相离、包含、重合、相切与相交五种情况。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::{Round, Intersection};
+    ///
+    ///     let a = Round::new(Point::new(0.0, 0.0), 2.0);
+    ///     let b = Round::new(Point::new(1.0, 0.0), 1.0);
+    ///     let c = Round::new(Point::new(0.5, 0.0), 1.0);
+    ///     assert_eq!(a.inter_round(b), Intersection::Tangent(Point::new(2.0, 0.0)));
+    ///     assert_eq!(a.inter_round(c), Intersection::Contained);
+    ///     assert_eq!(a.inter_round(a), Intersection::Coincident);
+    ///
+    pub fn inter_round(&self, rd: Round) -> Intersection {
         if self.o == rd.o {
-            return None
+            return if eq_f64(self.r, rd.r) { Intersection::Coincident } else { Intersection::Contained }
         }
         let odis = (self.o - rd.o).dis();
         if eq_f64(odis, (self.r - rd.r).abs()) {
@@ -50,19 +99,22 @@ impl Round {
             else {
                 rd.o + (self.o - rd.o).normalize() * rd.r
             };
-            return Some((ans, ans))
+            return Intersection::Tangent(ans)
         }
         if eq_f64(odis, self.r + rd.r) {
             let ans = self.o + (rd.o - self.o).normalize() * self.r;
-            return Some((ans, ans))
+            return Intersection::Tangent(ans)
         }
-        if odis < (self.r - rd.r).abs() || odis > self.r + rd.r {
-            return None
+        if odis < (self.r - rd.r).abs() {
+            return Intersection::Contained
         }
-        
+        if odis > self.r + rd.r {
+            return Intersection::Disjoint
+        }
+
         let theta = ((self.r * self.r + odis * odis - rd.r * rd.r) / (2.0 * self.r * odis)).acos();
-        Some((self.o + ((rd.o - self.o).normalize() * self.r).rot(theta),
-              self.o + ((rd.o - self.o).normalize() * self.r).rot(-theta)))
+        Intersection::Secant(self.o + ((rd.o - self.o).normalize() * self.r).rot(theta),
+                             self.o + ((rd.o - self.o).normalize() * self.r).rot(-theta))
     }
 
     /// 计算点到圆的切点。
@@ -109,9 +161,147 @@ impl Round {
             Line::new(self.o + alpha.rot(-theta) * self.r, rd.o + beta.rot(-theta) * rd.r),
         ))
     }
+
+    /// 计算两圆的所有公切线，按外公切线、内公切线的顺序返回 `(切线, 在该圆上的切点, 在圆 rd 上的切点)`。
+    ///
+    /// 两圆相离时有 4 条，外切时 3 条，相交时 2 条，内切时 1 条，内含时没有公切线；两圆重合时有无穷多条，返回空 `Vec`。
+    /// 两个切点不同时切线即为连接两切点的直线，两切点重合（两圆相切处）时切线经过该点并与连心线垂直。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///
+    ///     let a = Round::new(Point::new(0.0, 0.0), 1.0);
+    ///     let b = Round::new(Point::new(2.0, 0.0), 1.0);
+    ///     let v = a.common_tangents(b);
+    ///     assert_eq!(v.len(), 3);
+    ///     assert_eq!(v[2].1, Point::new(1.0, 0.0));
+    ///     assert_eq!(v[2].2, Point::new(1.0, 0.0));
+    ///
+    pub fn common_tangents(&self, rd: Round) -> Vec<(Line, Point, Point)> {
+        let d = rd.o - self.o;
+        let d2 = d.sqrdis();
+        if eq_f64(d2, 0.0) {
+            return Vec::new()
+        }
+
+        let mut res = Vec::new();
+        for r2 in [rd.r, -rd.r] {
+            let dr = self.r - r2;
+            let h2 = d2 - dr * dr;
+            if h2 < -EPS {
+                continue
+            }
+            let h = h2.max(0.0).sqrt();
+            let signs: &[f64] = if eq_f64(h2, 0.0) { &[1.0] } else { &[-1.0, 1.0] };
+            for &sign in signs {
+                let v = (d * dr + Point::new(-d.y, d.x) * (h * sign)) / d2;
+                let (p, q) = (self.o + v * self.r, rd.o + v * r2);
+                let l = if p == q { Line::new(p, p + Point::new(-v.y, v.x)) } else { Line::new(p, q) };
+                res.push((l, p, q));
+            }
+        }
+        res
+    }
+
+    /// 圆的面积。
+    pub fn area(&self) -> f64 {
+        std::f64::consts::PI * self.r * self.r
+    }
+
+    /// 计算两圆相交部分的面积。
+    ///
+    ///     use rust_geometry::eq_f64;
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///
+    ///     let a = Round::new(Point::new(0.0, 0.0), 1.0);
+    ///     let b = Round::new(Point::new(1.0, 0.0), 1.0);
+    ///     let s = 2.0 * std::f64::consts::PI / 3.0 - 3.0_f64.sqrt() / 2.0;
+    ///     assert!(eq_f64(a.intersection_area(b), s));
+    ///
+    pub fn intersection_area(&self, other: Round) -> f64 {
+        let d = (self.o - other.o).dis();
+        if d > self.r + other.r - EPS {
+            return 0.0
+        }
+        if d < (self.r - other.r).abs() + EPS {
+            let r = self.r.min(other.r);
+            return std::f64::consts::PI * r * r
+        }
+
+        // 两个弓形面积之和，每个弓形为扇形减去三角形。
+        let segment = |r1: f64, r2: f64| {
+            let alpha = ((r1 * r1 + d * d - r2 * r2) / (2.0 * r1 * d)).clamp(-1.0, 1.0).acos();
+            r1 * r1 * (alpha - alpha.sin() * alpha.cos())
+        };
+        segment(self.r, other.r) + segment(other.r, self.r)
+    }
+
+    /// 计算圆与三角形 `(o, a, b)` 相交部分的有向面积，`a`、`b` 为绝对坐标。
+    fn triangle_intersection_area(&self, a: Point, b: Point) -> f64 {
+        let l = Line::new(a, b);
+        if !l.valid() {
+            return 0.0
+        }
+
+        // 用圆与直线的交点把线段切成若干段，每段要么完全在圆内（取三角形），要么完全在圆外（取扇形）。
+        let mut cuts: Vec<f64> = self.inter_line(l).points().iter()
+            .map(|&p| (p - a) * l.vec() / l.sqrlen())
+            .filter(|&t| t > 0.0 && t < 1.0)
+            .collect();
+        cuts.sort_by(f64::total_cmp);
+        cuts.insert(0, 0.0);
+        cuts.push(1.0);
+
+        let mut ans = 0.0;
+        for w in cuts.windows(2) {
+            let p = a + l.vec() * w[0] - self.o;
+            let q = a + l.vec() * w[1] - self.o;
+            if ((p + q) / 2.0).dis() < self.r {
+                ans += (p ^ q) / 2.0;
+            }
+            else {
+                ans += self.r * self.r * p.rad(q) / 2.0;
+            }
+        }
+        ans
+    }
+
+    /// 计算圆与简单多边形相交部分的面积，多边形顶点按顺时针或逆时针顺序给出均可。
+    ///
+    /// 以圆心为公共顶点把多边形拆成若干有向三角形，分别与圆求交后求和。
+    ///
+    ///     use rust_geometry::eq_f64;
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///
+    ///     let rd = Round::new(Point::new(0.0, 0.0), 1.0);
+    ///     let square = vec![Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(2.0, 2.0), Point::new(0.0, 2.0)];
+    ///     assert!(eq_f64(rd.polygon_intersection_area(&square), std::f64::consts::PI / 4.0));
+    ///
+    pub fn polygon_intersection_area(&self, pts: &[Point]) -> f64 {
+        let n = pts.len();
+        (0 .. n).map(|i| self.triangle_intersection_area(pts[i], pts[(i + 1) % n])).sum::<f64>().abs()
+    }
+
+    /// 计算圆与凸包相交部分的面积。
+    ///
+    ///     use rust_geometry::eq_f64;
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///     use rust_geometry::convex_hull::ConvexHull;
+    ///
+    ///     let hull = ConvexHull::get_convex_hull(vec![Point::new(-1.0, -1.0), Point::new(1.0, -1.0),
+    ///                                                 Point::new(1.0, 1.0), Point::new(-1.0, 1.0)]);
+    ///     let rd = Round::new(Point::new(0.0, 0.0), 0.5);
+    ///     assert!(eq_f64(rd.convex_hull_intersection_area(&hull), rd.area()));
+    ///
+    pub fn convex_hull_intersection_area(&self, hull: &ConvexHull) -> f64 {
+        self.polygon_intersection_area(&hull.get_points())
+    }
 }
 
-/// 计算三个点构成三角形的内心
+/// 计算三个点构成三角形的内心，需要内切圆半径时可使用 `Triangle::incircle`。
 ///
 ///     use rust_geometry::eq_f64;
 ///     use rust_geometry::point::Point;
@@ -139,7 +329,7 @@ pub fn incentre(a: Point, b: Point, c: Point) -> Point {
     (a * la + b * lb + c * lc) / (la + lb + lc)
 }
 
-/// 计算三个点构成三角形的外心
+/// 计算三个点构成三角形的外心，需要外接圆半径时可使用 `Triangle::circumcircle`。
 ///
 ///     use rust_geometry::point::Point;
 ///     use rust_geometry::round::circum;
@@ -168,10 +358,156 @@ pub fn circum(a: Point, b: Point, c: Point) -> Option<Point> {
     Some(Point { x, y })
 }
 
+/// 计算若干圆的并中每个圆没有被其他圆覆盖的圆弧，返回 `(圆的下标, 起始角, 终止角)`，角度为弧度制且起始角小于终止角。
+///
+/// 重合的圆只保留下标最小的一个，被其他圆包含的圆没有圆弧。
+fn uncovered_arcs(rds: &[Round]) -> Vec<(usize, f64, f64)> {
+    use std::f64::consts::PI;
+
+    let angle = |rd: &Round, p: Point| {
+        let t = (p - rd.o).polar_angle();
+        if t < 0.0 { t + 2.0 * PI } else { t }
+    };
+
+    let mut arcs = Vec::new();
+    for (i, a) in rds.iter().enumerate() {
+        let hidden = rds.iter().enumerate().any(|(j, b)| {
+            let d = (a.o - b.o).dis();
+            if a.o == b.o && eq_f64(a.r, b.r) { j < i } else { d + a.r < b.r + EPS }
+        });
+        if hidden {
+            continue
+        }
+
+        let mut covered: Vec<(f64, f64)> = Vec::new();
+        for b in rds {
+            if let Intersection::Secant(p, q) = a.inter_round(*b) {
+                let (s, t) = (angle(a, p), angle(a, q));
+                // 两交点把圆周分成两段，取中点落在圆 b 内的一段。
+                let mid = if s < t { (s + t) / 2.0 } else { (s + t) / 2.0 + PI };
+                let (s, t) = if (a.o + Point::new(a.r, 0.0).rot(mid) - b.o).dis() < b.r { (s, t) } else { (t, s) };
+                if s < t {
+                    covered.push((s, t));
+                }
+                else {
+                    covered.push((s, 2.0 * PI));
+                    covered.push((0.0, t));
+                }
+            }
+        }
+        covered.sort_by(|x, y| x.0.total_cmp(&y.0));
+
+        let mut last = 0.0;
+        for (s, t) in covered {
+            if s > last {
+                arcs.push((i, last, s));
+            }
+            last = f64::max(last, t);
+        }
+        if last < 2.0 * PI {
+            arcs.push((i, last, 2.0 * PI));
+        }
+    }
+    arcs
+}
+
+/// 计算若干圆的并的面积。
+///
+/// 只有未被其他圆覆盖的圆弧构成并的边界，根据格林公式沿这些圆弧积分即可得到面积，时间复杂度 O(n^2 log n)。
+///
+///     use rust_geometry::eq_f64;
+///     use rust_geometry::point::Point;
+///     use rust_geometry::round::{Round, round_union_area};
+///
+///     let a = Round::new(Point::new(0.0, 0.0), 1.0);
+///     let b = Round::new(Point::new(1.0, 0.0), 1.0);
+///     let s = a.area() + b.area() - a.intersection_area(b);
+///     assert!(eq_f64(round_union_area(&[a, b]), s));
+///
+pub fn round_union_area(rds: &[Round]) -> f64 {
+    uncovered_arcs(rds).into_iter().map(|(i, s, t)| {
+        let Round { o, r } = rds[i];
+        r * r * (t - s) + r * (o.x * (t.sin() - s.sin()) - o.y * (t.cos() - s.cos()))
+    }).sum::<f64>() / 2.0
+}
+
+/// 计算若干圆的并的周长，即所有未被其他圆覆盖的圆弧的长度之和。
+///
+///     use rust_geometry::eq_f64;
+///     use rust_geometry::point::Point;
+///     use rust_geometry::round::{Round, round_union_perimeter};
+///
+///     let a = Round::new(Point::new(0.0, 0.0), 1.0);
+///     let b = Round::new(Point::new(1.0, 0.0), 1.0);
+///     assert!(eq_f64(round_union_perimeter(&[a, b]), 8.0 * std::f64::consts::PI / 3.0));
+///
+pub fn round_union_perimeter(rds: &[Round]) -> f64 {
+    uncovered_arcs(rds).into_iter().map(|(i, s, t)| rds[i].r * (t - s)).sum()
+}
+
+/// 求一个半径为 `r` 的圆，使其覆盖（含边界）的点数最多，返回该圆与覆盖的点数。
+///
+/// 最优圆总可以平移到边界经过某个点，于是枚举边界上的点 `p`，圆心在以 `p` 为圆心、`r` 为半径的圆上转动，
+/// 其余每个点在圆心转到某段弧上时被覆盖，弧的端点由 `inter_round` 求出，对这些端点做角度扫描，时间复杂度 O(n^2 log n)。
+///
+///     use rust_geometry::point::Point;
+///     use rust_geometry::round::max_covering_round;
+///
+///     let pts = vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(0.0, 1.0), Point::new(5.0, 5.0)];
+///     let (rd, cnt) = max_covering_round(&pts, 1.0);
+///     assert_eq!(cnt, 3);
+///     assert!(pts[.. 3].iter().all(|&p| (p - rd.o).dis() < rd.r + 1e-9));
+///
+pub fn max_covering_round(pts: &[Point], r: f64) -> (Round, usize) {
+    let mut best = (Round::new(pts.first().copied().unwrap_or(Point::new(0.0, 0.0)), r), pts.len().min(1));
+    let angle = |o: Point, p: Point| (p - o).polar_angle();
+
+    for &p in pts {
+        let c = Round::new(p, r);
+        let mut base = pts.iter().filter(|&&q| q == p).count();
+        let mut events: Vec<(f64, i32)> = Vec::new();
+        for &q in pts.iter().filter(|&&q| q != p) {
+            match c.inter_round(Round::new(q, r)) {
+                Intersection::Tangent(x) => {
+                    let t = angle(p, x);
+                    events.push((t, 1));
+                    events.push((t, -1));
+                }
+                Intersection::Secant(x, y) => {
+                    // 两个交点之间中点更靠近 q 的那段弧上的圆心能覆盖 q。
+                    let (s, t) = (angle(p, x), angle(p, y));
+                    let mid = (s + t) / 2.0 + if s < t { 0.0 } else { std::f64::consts::PI };
+                    let (s, t) = if (p + Point::new(r, 0.0).rot(mid) - q).dis() < r { (s, t) } else { (t, s) };
+                    if s > t {
+                        base += 1;
+                    }
+                    events.push((s, 1));
+                    events.push((t, -1));
+                }
+                _ => {}
+            }
+        }
+
+        // 同一角度处先进入再离开，保证相切的点也被计入。
+        events.sort_by(|a, b| a.0.total_cmp(&b.0).then(b.1.cmp(&a.1)));
+        let mut cnt = base;
+        if cnt > best.1 {
+            best = (Round::new(p + Point::new(-r, 0.0), r), cnt);
+        }
+        for (t, d) in events {
+            cnt = cnt.checked_add_signed(d as isize).expect("more exit events than entry events");
+            if cnt > best.1 {
+                best = (Round::new(p + Point::new(r, 0.0).rot(t), r), cnt);
+            }
+        }
+    }
+    best
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util::Lcg;
 
     #[test]
     fn inter_test() {
@@ -183,13 +519,28 @@ mod tests {
         let inter_pt = rd.inter_line(l);
         let inter_rd = rd.inter_round(rd2);
 
-        let ans1 = Some((Point::new(1.0, 0.0), Point::new(-1.0, 0.0)));
-        let ans2 = Some((Point::new(-1.0, 0.0), Point::new(1.0, 0.0)));
+        let ans1 = Intersection::Secant(Point::new(1.0, 0.0), Point::new(-1.0, 0.0));
+        let ans2 = Intersection::Secant(Point::new(-1.0, 0.0), Point::new(1.0, 0.0));
 
         assert!(inter_pt == ans1 || inter_pt == ans2);
         assert!(inter_rd == ans1 || inter_rd == ans2);
     }
 
+    #[test]
+    fn inter_cases_test() {
+        let rd = Round::new(Point::new(0.0, 0.0), 2.0);
+        let far = Line::new(Point::new(0.0, 3.0), Point::new(1.0, 3.0));
+        assert_eq!(rd.inter_line(far), Intersection::Disjoint);
+        assert!(rd.inter_line(far).points().is_empty());
+
+        assert_eq!(rd.inter_round(Round::new(Point::new(5.0, 0.0), 1.0)), Intersection::Disjoint);
+        assert_eq!(rd.inter_round(Round::new(Point::new(3.0, 0.0), 1.0)), Intersection::Tangent(Point::new(2.0, 0.0)));
+        assert_eq!(rd.inter_round(Round::new(Point::new(0.0, 0.0), 1.0)), Intersection::Contained);
+        assert_eq!(Round::new(Point::new(0.0, 0.5), 1.0).inter_round(rd), Intersection::Contained);
+        assert_eq!(Round::new(Point::new(0.0, 1.0), 1.0).inter_round(rd), Intersection::Tangent(Point::new(0.0, 2.0)));
+        assert_eq!(rd.inter_round(rd).points().len(), 0);
+    }
+
     #[test]
     fn tangent_points_and_lines_test() {
         let rd1 = Round::new(Point::new(15.0, 73.0), 7.0);
@@ -241,4 +592,116 @@ mod tests {
             None => panic!("unexpected result")
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn intersection_area_test() {
+        let a = Round::new(Point::new(0.0, 0.0), 2.0);
+        assert!(eq_f64(a.intersection_area(Round::new(Point::new(5.0, 0.0), 1.0)), 0.0));
+        assert!(eq_f64(a.intersection_area(Round::new(Point::new(0.5, 0.0), 1.0)), std::f64::consts::PI));
+        assert!(eq_f64(a.intersection_area(a), a.area()));
+
+        // 正方形包含圆、圆包含正方形，以及圆心在多边形边界上的情形。
+        let square = vec![Point::new(-1.0, -1.0), Point::new(1.0, -1.0), Point::new(1.0, 1.0), Point::new(-1.0, 1.0)];
+        let pi = std::f64::consts::PI;
+        assert!(eq_f64(Round::new(Point::new(0.0, 0.0), 1.0).polygon_intersection_area(&square), pi));
+        assert!(eq_f64(Round::new(Point::new(0.0, 0.0), 2.0).polygon_intersection_area(&square), 4.0));
+        assert!(eq_f64(Round::new(Point::new(1.0, 0.0), 1.0).polygon_intersection_area(&square), pi / 2.0));
+        assert!(eq_f64(Round::new(Point::new(3.0, 0.0), 1.0).polygon_intersection_area(&square), 0.0));
+
+        // 顺时针给出的凹多边形。
+        let concave = vec![Point::new(0.0, 0.0), Point::new(0.0, 2.0), Point::new(1.0, 1.0),
+                           Point::new(2.0, 2.0), Point::new(2.0, 0.0)];
+        let rd = Round::new(Point::new(1.0, 1.0), 10.0);
+        assert!(eq_f64(rd.polygon_intersection_area(&concave), 3.0));
+    }
+
+    #[test]
+    fn union_test() {
+        use std::f64::consts::PI;
+
+        let a = Round::new(Point::new(0.0, 0.0), 2.0);
+        let inner = Round::new(Point::new(0.5, 0.5), 1.0);
+        let touching = Round::new(Point::new(1.0, 0.0), 1.0);
+        let far = Round::new(Point::new(10.0, 0.0), 1.0);
+        let rds = vec![a, inner, touching, a, far];
+        assert!(eq_f64(round_union_area(&rds), 5.0 * PI));
+        assert!(eq_f64(round_union_perimeter(&rds), 6.0 * PI));
+
+        // 三个两两相交的圆，用容斥原理检验。三圆公共部分由三段圆弧围成，
+        // 其面积为三个交点构成的三角形面积加上三个弓形面积。
+        let b = Round::new(Point::new(2.0, 0.0), 2.0);
+        let c = Round::new(Point::new(1.0, 1.5), 1.5);
+        let corner = |x: Round, y: Round, z: Round| match x.inter_round(y) {
+            Intersection::Secant(p, q) => if (p - z.o).dis() < z.r { p } else { q },
+            _ => panic!("circles should be secant"),
+        };
+        let segment = |rd: Round, p: Point, q: Point| {
+            let t = 2.0 * ((p - q).dis() / (2.0 * rd.r)).asin();
+            rd.r * rd.r / 2.0 * (t - t.sin())
+        };
+        let (pab, pbc, pca) = (corner(a, b, c), corner(b, c, a), corner(c, a, b));
+        let triple = ((pbc - pab) ^ (pca - pab)).abs() / 2.0
+            + segment(a, pab, pca) + segment(b, pab, pbc) + segment(c, pbc, pca);
+        let expected = a.area() + b.area() + c.area()
+            - a.intersection_area(b) - b.intersection_area(c) - c.intersection_area(a) + triple;
+        let s = round_union_area(&[a, b, c]);
+        assert!((s - expected).abs() < EPS * 100.0);
+        let pair = a.area() + b.area() - a.intersection_area(b);
+        assert!(eq_f64(round_union_area(&[a, b]), pair));
+        assert!(eq_f64(round_union_area(&[]), 0.0));
+    }
+
+    #[test]
+    fn common_tangents_test() {
+        let a = Round::new(Point::new(0.0, 0.0), 2.0);
+        let cases = [(Round::new(Point::new(5.0, 1.0), 1.0), 4), (Round::new(Point::new(3.0, 0.0), 1.0), 3),
+                     (Round::new(Point::new(2.0, 1.0), 1.0), 2), (Round::new(Point::new(1.0, 0.0), 1.0), 1),
+                     (Round::new(Point::new(0.5, 0.0), 1.0), 0), (a, 0)];
+        for (b, cnt) in cases {
+            let v = a.common_tangents(b);
+            assert_eq!(v.len(), cnt);
+            for (l, p, q) in v {
+                assert!(l.valid());
+                assert!(eq_f64((l.proj(a.o) - a.o).dis(), a.r));
+                assert!(eq_f64((l.proj(b.o) - b.o).dis(), b.r));
+                assert_eq!(l.proj(a.o), p);
+                assert_eq!(l.proj(b.o), q);
+            }
+        }
+    }
+
+    #[test]
+    fn max_covering_test() {
+        let mut rng = Lcg::new(17);
+        let mut next = || rng.below(20) as f64 / 2.0;
+        let pts: Vec<Point> = (0 .. 60).map(|_| Point::new(next(), next())).collect();
+        let r = 1.5;
+        let cover = |o: Point| pts.iter().filter(|&&p| (p - o).dis() < r + EPS).count();
+
+        // 暴力枚举所有经过两点或以某点为圆心的候选圆。
+        let mut ans = 0;
+        for &p in &pts {
+            ans = ans.max(cover(p));
+            for &q in &pts {
+                for o in Round::new(p, r).inter_round(Round::new(q, r)).points() {
+                    ans = ans.max(cover(o));
+                }
+            }
+        }
+        let (rd, cnt) = max_covering_round(&pts, r);
+        assert_eq!(cnt, ans);
+        assert_eq!(cover(rd.o), ans);
+        assert_eq!(max_covering_round(&[], 1.0).1, 0);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_test() {
+        let rd = Round::new(Point::new(1.0, -2.5), 3.0);
+        let s = serde_json::to_string(&rd).unwrap();
+        assert_eq!(s, r#"{"o":{"x":1.0,"y":-2.5},"r":3.0}"#);
+        let back: Round = serde_json::from_str(&s).unwrap();
+        assert_eq!(back.o, rd.o);
+        assert!(eq_f64(back.r, rd.r));
+    }
+}
@@ -0,0 +1,437 @@
+use crate::*;
+use std::fmt;
+use std::cmp::PartialEq;
+use std::ops::{Add, Sub, Mul, Div, Neg, BitXor};
+use std::f64::consts::PI;
+
+/// `Point3` 三维空间中的点（向量），与 `Point` 的设计一致，点乘与叉乘的运算符号分别为 `*` 和 `^`，其中叉乘的结果为向量。
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Point3 {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl PartialEq for Point3 {
+    /// 基于 `EPS` 常量，定义了两个点（向量）的相等关系。
+    fn eq(&self, p: &Self) -> bool {
+        eq_f64(self.x, p.x) && eq_f64(self.y, p.y) && eq_f64(self.z, p.z)
+    }
+}
+
+impl fmt::Display for Point3 {
+    /// 支持点的输出。
+    ///
+    ///     use rust_geometry::space::Point3;
+    ///
+    ///     let p = Point3::new(0.0, 0.0, 1.0);
+    ///     println!("{}", p); // (0.00000,0.00000,1.00000)
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({:.5},{:.5},{:.5})", self.x, self.y, self.z)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    /// 实现向量的加法。
+    fn add(self, p: Self) -> Self {
+        Self {
+            x: self.x + p.x,
+            y: self.y + p.y,
+            z: self.z + p.z,
+        }
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    /// 实现向量的减法。
+    fn sub(self, p: Self) -> Self {
+        Self {
+            x: self.x - p.x,
+            y: self.y - p.y,
+            z: self.z - p.z,
+        }
+    }
+}
+
+impl Neg for Point3 {
+    type Output = Self;
+
+    /// 实现向量的取负。
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl Mul<f64> for Point3 {
+    type Output = Self;
+
+    /// 实现向量与常数的乘法。
+    fn mul(self, a: f64) -> Self {
+        Self {
+            x: self.x * a,
+            y: self.y * a,
+            z: self.z * a,
+        }
+    }
+}
+
+impl Mul for Point3 {
+    type Output = f64;
+
+    /// 实现向量与向量的点乘，结果为浮点数。
+    ///
+    ///     use rust_geometry::eq_f64;
+    ///     use rust_geometry::space::Point3;
+    ///
+    ///     let a = Point3::new(1.0, 2.0, 3.0);
+    ///     let b = Point3::new(4.0, 5.0, 6.0);
+    ///     assert!(eq_f64(a * b, 32.0));
+    ///
+    fn mul(self, p: Self) -> Self::Output {
+        self.x * p.x + self.y * p.y + self.z * p.z
+    }
+}
+
+impl Div<f64> for Point3 {
+    type Output = Self;
+
+    /// 实现向量和常数的除法。
+    fn div(self, a: f64) -> Self {
+        Self {
+            x: self.x / a,
+            y: self.y / a,
+            z: self.z / a,
+        }
+    }
+}
+
+impl BitXor for Point3 {
+    type Output = Self;
+
+    /// 实现向量与向量的叉乘，结果为同时垂直于两向量的向量。
+    ///
+    ///     use rust_geometry::space::Point3;
+    ///
+    ///     let a = Point3::new(1.0, 0.0, 0.0);
+    ///     let b = Point3::new(0.0, 1.0, 0.0);
+    ///     assert_eq!(a ^ b, Point3::new(0.0, 0.0, 1.0));
+    ///
+    fn bitxor(self, p: Self) -> Self::Output {
+        Self {
+            x: self.y * p.z - self.z * p.y,
+            y: self.z * p.x - self.x * p.z,
+            z: self.x * p.y - self.y * p.x,
+        }
+    }
+}
+
+impl Point3 {
+    /// 根据坐标初始化点（向量）对象。
+    pub fn new(x: f64, y: f64, z: f64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    /// 点到原点的距离，向量的模长。
+    pub fn dis(&self) -> f64 {
+        ((*self) * (*self)).sqrt()
+    }
+
+    /// 点到原点的距离的平方，向量模长的平方。
+    pub fn sqrdis(&self) -> f64 {
+        (*self) * (*self)
+    }
+
+    /// 向量归一化。
+    pub fn normalize(&self) -> Self {
+        *self / self.dis()
+    }
+
+    /// 计算两个向量之间的夹角，返回角度为弧度制，在 [0, pi] 之间。
+    pub fn angle(&self, p: Point3) -> f64 {
+        (*self ^ p).dis().atan2(*self * p)
+    }
+}
+
+/// `Line3` 三维空间中的直线（线段），由两点 `a`、`b` 表示。
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Line3 {
+    pub a: Point3,
+    pub b: Point3,
+}
+
+impl fmt::Display for Line3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.a, self.b)
+    }
+}
+
+impl Line3 {
+    /// 通过两点构造直线。
+    pub fn new(a: Point3, b: Point3) -> Self {
+        Line3 { a, b }
+    }
+
+    /// 判断是否为合法直线（两点间距离是否过小）。
+    pub fn valid(&self) -> bool {
+        !eq_f64(self.vec().dis(), 0.0)
+    }
+
+    /// 计算两点构成向量。
+    pub fn vec(&self) -> Point3 {
+        self.b - self.a
+    }
+
+    /// 计算线段长度。
+    pub fn len(&self) -> f64 {
+        self.vec().dis()
+    }
+
+    /// 计算点到直线的投影。
+    pub fn proj(&self, p: Point3) -> Point3 {
+        self.a + self.vec() * (((p - self.a) * self.vec()) / self.vec().sqrdis())
+    }
+
+    /// 计算点到直线的距离。
+    pub fn dis(&self, p: Point3) -> f64 {
+        ((p - self.a) ^ self.vec()).dis() / self.len()
+    }
+
+    /// 计算两条直线上距离最近的一对点，依次位于 `self` 与 `l` 上；两直线平行或重合时返回 `None`。
+    ///
+    ///     use rust_geometry::space::{Point3, Line3};
+    ///
+    ///     let la = Line3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 0.0, 0.0));
+    ///     let lb = Line3::new(Point3::new(0.0, 1.0, 2.0), Point3::new(0.0, 2.0, 2.0));
+    ///     let (p, q) = la.closest_points(lb).unwrap();
+    ///     assert_eq!(p, Point3::new(0.0, 0.0, 0.0));
+    ///     assert_eq!(q, Point3::new(0.0, 0.0, 2.0));
+    ///
+    pub fn closest_points(&self, l: Line3) -> Option<(Point3, Point3)> {
+        let (u, v, w) = (self.vec(), l.vec(), self.a - l.a);
+        if eq_f64((u ^ v).dis(), 0.0) {
+            return None
+        }
+        let (a, b, c, d, e) = (u * u, u * v, v * v, u * w, v * w);
+        let den = a * c - b * b;
+        Some((self.a + u * ((b * e - c * d) / den), l.a + v * ((a * e - b * d) / den)))
+    }
+
+    /// 计算两条直线之间的距离，适用于相交、平行与异面的情况。
+    pub fn dis_line(&self, l: Line3) -> f64 {
+        match self.closest_points(l) {
+            Some((p, q)) => (p - q).dis(),
+            None => self.dis(l.a),
+        }
+    }
+
+    /// 计算两条直线的交点，两直线平行、重合或异面时返回 `None`。
+    pub fn inter(&self, l: Line3) -> Option<Point3> {
+        let (p, q) = self.closest_points(l)?;
+        if p == q { Some(p) } else { None }
+    }
+}
+
+/// `Plane` 三维空间中的平面，由平面上一点 `o` 与法向量 `n` 表示，法向量不要求是单位向量。
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Plane {
+    pub o: Point3,
+    pub n: Point3,
+}
+
+impl fmt::Display for Plane {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}|{}", self.o, self.n)
+    }
+}
+
+impl Plane {
+    /// 通过平面上一点与法向量构造平面。
+    pub fn new(o: Point3, n: Point3) -> Self {
+        Plane { o, n }
+    }
+
+    /// 通过不共线的三点构造平面，法向量为 `(b - a) ^ (c - a)`，三点共线时返回 `None`。
+    pub fn from_points(a: Point3, b: Point3, c: Point3) -> Option<Self> {
+        let n = (b - a) ^ (c - a);
+        if eq_f64(n.dis(), 0.0) {
+            return None
+        }
+        Some(Plane { o: a, n })
+    }
+
+    /// 点到平面的有向距离，点在法向量所指一侧时为正。
+    pub fn side(&self, p: Point3) -> f64 {
+        (p - self.o) * self.n / self.n.dis()
+    }
+
+    /// 点到平面的距离。
+    pub fn dis(&self, p: Point3) -> f64 {
+        self.side(p).abs()
+    }
+
+    /// 计算点到平面的投影。
+    pub fn proj(&self, p: Point3) -> Point3 {
+        p - self.n * ((p - self.o) * self.n / self.n.sqrdis())
+    }
+
+    /// 计算直线与平面的交点，直线与平面平行或在平面内时返回 `None`。
+    ///
+    ///     use rust_geometry::space::{Point3, Line3, Plane};
+    ///
+    ///     let pl = Plane::new(Point3::new(0.0, 0.0, 1.0), Point3::new(0.0, 0.0, 1.0));
+    ///     let l = Line3::new(Point3::new(0.0, 0.0, 0.0), Point3::new(1.0, 1.0, 2.0));
+    ///     assert_eq!(pl.inter_line(l), Some(Point3::new(0.5, 0.5, 1.0)));
+    ///
+    pub fn inter_line(&self, l: Line3) -> Option<Point3> {
+        let d = l.vec() * self.n;
+        if eq_f64(d, 0.0) {
+            return None
+        }
+        Some(l.a + l.vec() * ((self.o - l.a) * self.n / d))
+    }
+
+    /// 计算两个平面的交线，两平面平行或重合时返回 `None`。
+    pub fn inter_plane(&self, pl: Plane) -> Option<Line3> {
+        let d = self.n ^ pl.n;
+        if eq_f64(d.dis(), 0.0) {
+            return None
+        }
+        let (d1, d2) = (self.n * self.o, pl.n * pl.o);
+        let p = ((pl.n ^ d) * d1 + (d ^ self.n) * d2) / d.sqrdis();
+        Some(Line3::new(p, p + d))
+    }
+}
+
+/// `Sphere` 球，由球心 `o` 与半径 `r` 表示。
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sphere {
+    pub o: Point3,
+    pub r: f64,
+}
+
+impl fmt::Display for Sphere {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{} {}]", self.o, self.r)
+    }
+}
+
+impl Sphere {
+    /// 通过球心与半径构造球。
+    pub fn new(o: Point3, r: f64) -> Self {
+        Sphere { o, r }
+    }
+
+    /// 球的体积。
+    pub fn volume(&self) -> f64 {
+        4.0 / 3.0 * PI * self.r.powi(3)
+    }
+
+    /// 球的表面积。
+    pub fn surface_area(&self) -> f64 {
+        4.0 * PI * self.r * self.r
+    }
+
+    /// 判断点是否在球内（含球面，基于 `EPS`）。
+    pub fn contains(&self, p: Point3) -> bool {
+        (p - self.o).dis() < self.r + EPS
+    }
+
+    /// 计算直线与球面的交点，相切时返回一个点，相离时返回空。
+    ///
+    ///     use rust_geometry::space::{Point3, Line3, Sphere};
+    ///
+    ///     let s = Sphere::new(Point3::new(0.0, 0.0, 0.0), 1.0);
+    ///     let l = Line3::new(Point3::new(0.0, 0.0, -3.0), Point3::new(0.0, 0.0, 3.0));
+    ///     assert_eq!(s.inter_line(l), vec![Point3::new(0.0, 0.0, -1.0), Point3::new(0.0, 0.0, 1.0)]);
+    ///
+    pub fn inter_line(&self, l: Line3) -> Vec<Point3> {
+        let f = l.proj(self.o);
+        let d = (f - self.o).dis();
+        if eq_f64(d, self.r) {
+            return vec![f]
+        }
+        if d > self.r {
+            return Vec::new()
+        }
+        let v = l.vec().normalize() * (self.r * self.r - d * d).sqrt();
+        vec![f - v, f + v]
+    }
+
+    /// 计算平面与球面的交线，返回交线圆的圆心与半径（相切时半径为 0），相离时返回 `None`。
+    pub fn inter_plane(&self, pl: Plane) -> Option<(Point3, f64)> {
+        let d = pl.dis(self.o);
+        if eq_f64(d, self.r) {
+            return Some((pl.proj(self.o), 0.0))
+        }
+        if d > self.r {
+            return None
+        }
+        Some((pl.proj(self.o), (self.r * self.r - d * d).sqrt()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_test() {
+        let la = Line3::new(Point3::new(1.0, 2.0, 0.0), Point3::new(3.0, -1.0, 4.0));
+        let lb = Line3::new(Point3::new(-2.0, 0.5, 1.0), Point3::new(0.0, 3.0, -2.0));
+        let (p, q) = la.closest_points(lb).unwrap();
+        assert!(eq_f64((q - p) * la.vec(), 0.0));
+        assert!(eq_f64((q - p) * lb.vec(), 0.0));
+        assert!(eq_f64(la.dis_line(lb), (q - p).dis()));
+        assert!(la.inter(lb).is_none());
+        assert!(eq_f64(la.dis(q), (q - p).dis()));
+
+        let lc = Line3::new(p, Point3::new(5.0, 5.0, 5.0));
+        assert_eq!(la.inter(lc), Some(p));
+
+        let par = Line3::new(la.a + Point3::new(0.0, 0.0, 1.0), la.b + Point3::new(0.0, 0.0, 1.0));
+        assert!(par.closest_points(la).is_none());
+        assert!(eq_f64(par.dis_line(la), la.dis(par.a)));
+    }
+
+    #[test]
+    fn plane_sphere_test() {
+        let pa = Plane::from_points(Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0), Point3::new(0.0, 0.0, 1.0)).unwrap();
+        assert!(eq_f64(pa.side(Point3::new(0.0, 0.0, 0.0)), -(1.0 / 3.0_f64).sqrt()));
+        assert_eq!(pa.proj(Point3::new(0.0, 0.0, 0.0)), Point3::new(1.0, 1.0, 1.0) / 3.0);
+
+        let pb = Plane::new(Point3::new(0.0, 0.0, 0.5), Point3::new(0.0, 0.0, 2.0));
+        let l = pa.inter_plane(pb).unwrap();
+        for t in [-1.0, 0.5, 2.0] {
+            let p = l.a + l.vec() * t;
+            assert!(eq_f64(pa.side(p), 0.0) && eq_f64(pb.side(p), 0.0));
+        }
+        assert!(pb.inter_plane(Plane::new(Point3::new(1.0, 1.0, 1.0), Point3::new(0.0, 0.0, -1.0))).is_none());
+
+        let s = Sphere::new(Point3::new(1.0, 1.0, 1.0), 2.0);
+        assert!(eq_f64(s.volume(), 32.0 / 3.0 * PI));
+        let (c, r) = s.inter_plane(pb).unwrap();
+        assert_eq!(c, Point3::new(1.0, 1.0, 0.5));
+        assert!(eq_f64(r, 3.75_f64.sqrt()));
+        assert!(s.inter_plane(Plane::new(Point3::new(0.0, 0.0, 4.0), pb.n)).is_none());
+
+        let v = s.inter_line(Line3::new(Point3::new(-3.0, 0.0, 1.0), Point3::new(5.0, 2.0, 1.0)));
+        assert_eq!(v.len(), 2);
+        for p in v {
+            assert!(eq_f64((p - s.o).dis(), s.r));
+        }
+        assert_eq!(s.inter_line(Line3::new(Point3::new(3.0, 0.0, 1.0), Point3::new(3.0, 2.0, 1.0))).len(), 1);
+    }
+}
@@ -0,0 +1,112 @@
+use crate::*;
+use crate::space::*;
+
+/// 将纬度 `lat`、经度 `lon`（弧度制）转换为单位球面上的点。
+///
+/// 坐标系以球心为原点，z 轴指向北极，x 轴指向经度 0 处的赤道。
+///
+///     use rust_geometry::space::Point3;
+///     use rust_geometry::spherical::from_lat_lon;
+///
+///     let p = from_lat_lon(0.0, 90.0_f64.to_radians());
+///     assert_eq!(p, Point3::new(0.0, 1.0, 0.0));
+///
+pub fn from_lat_lon(lat: f64, lon: f64) -> Point3 {
+    Point3::new(lat.cos() * lon.cos(), lat.cos() * lon.sin(), lat.sin())
+}
+
+/// 将球面上的点（不要求是单位向量）转换为纬度与经度（弧度制），纬度在 [-pi/2, pi/2] 之间，经度在 (-pi, pi] 之间。
+///
+///     use rust_geometry::eq_f64;
+///     use rust_geometry::spherical::{from_lat_lon, to_lat_lon};
+///
+///     let (lat, lon) = to_lat_lon(from_lat_lon(0.5, -2.0));
+///     assert!(eq_f64(lat, 0.5) && eq_f64(lon, -2.0));
+///
+pub fn to_lat_lon(p: Point3) -> (f64, f64) {
+    (p.z.atan2(p.x.hypot(p.y)), p.y.atan2(p.x))
+}
+
+/// 单位球面上两点之间的大圆距离，即两向量的夹角，乘以球的半径即为实际距离。
+///
+///     use rust_geometry::eq_f64;
+///     use rust_geometry::spherical::{from_lat_lon, great_circle_distance};
+///
+///     let a = from_lat_lon(90.0_f64.to_radians(), 0.0);
+///     let b = from_lat_lon(0.0, 1.0);
+///     assert!(eq_f64(great_circle_distance(a, b), std::f64::consts::PI / 2.0));
+///
+pub fn great_circle_distance(a: Point3, b: Point3) -> f64 {
+    a.angle(b)
+}
+
+/// 以三点为顶点、各边为劣弧的球面三角形在单位球面上的面积，即球面角盈。
+///
+/// 使用 Van Oosterom-Strackee 公式 `tan(E / 2) = |a (b x c)| / (1 + a b + b c + c a)`，对很小的三角形也有较好的精度。
+///
+///     use rust_geometry::eq_f64;
+///     use rust_geometry::space::Point3;
+///     use rust_geometry::spherical::spherical_triangle_area;
+///
+///     let (x, y, z) = (Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0), Point3::new(0.0, 0.0, 1.0));
+///     assert!(eq_f64(spherical_triangle_area(x, y, z), std::f64::consts::PI / 2.0));
+///
+pub fn spherical_triangle_area(a: Point3, b: Point3, c: Point3) -> f64 {
+    let (a, b, c) = (a.normalize(), b.normalize(), c.normalize());
+    2.0 * (a * (b ^ c)).abs().atan2(1.0 + a * b + b * c + c * a)
+}
+
+/// 计算过 `a1`、`a2` 的大圆与过 `b1`、`b2` 的大圆的交点，两交点关于球心对称；
+/// 两个大圆重合或某一对点无法确定大圆（重合或对径）时返回 `None`。
+///
+///     use rust_geometry::space::Point3;
+///     use rust_geometry::spherical::great_circle_inter;
+///
+///     let (x, y, z) = (Point3::new(1.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0), Point3::new(0.0, 0.0, 1.0));
+///     let (p, q) = great_circle_inter(x, y, y, z).unwrap();
+///     assert!(p == y && q == -y || p == -y && q == y);
+///
+pub fn great_circle_inter(a1: Point3, a2: Point3, b1: Point3, b2: Point3) -> Option<(Point3, Point3)> {
+    let (na, nb) = (a1 ^ a2, b1 ^ b2);
+    if eq_f64(na.dis(), 0.0) || eq_f64(nb.dis(), 0.0) {
+        return None
+    }
+    let d = na.normalize() ^ nb.normalize();
+    if eq_f64(d.dis(), 0.0) {
+        return None
+    }
+    let p = d.normalize();
+    Some((p, -p))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn spherical_test() {
+        // 北京与纽约之间的大圆距离约为 10988 公里。
+        let bj = from_lat_lon(39.9042_f64.to_radians(), 116.4074_f64.to_radians());
+        let ny = from_lat_lon(40.7128_f64.to_radians(), (-74.0060_f64).to_radians());
+        let d = great_circle_distance(bj, ny) * 6371.0;
+        assert!((d - 10988.0).abs() < 5.0);
+
+        // 顶点在北极、两边经度相差 theta 的月牙形，被赤道截出的三角形面积为 theta。
+        let np = Point3::new(0.0, 0.0, 1.0);
+        let (a, b) = (from_lat_lon(0.0, 0.3), from_lat_lon(0.0, 1.5));
+        assert!(eq_f64(spherical_triangle_area(np, a, b), 1.2));
+        assert!(eq_f64(spherical_triangle_area(a, np, b), 1.2));
+        assert!(spherical_triangle_area(a, a, b).abs() < EPS);
+
+        let (u, v) = (from_lat_lon(0.2, 0.0), from_lat_lon(0.0, PI / 2.0));
+        let (p, q) = great_circle_inter(np, a, u, v).unwrap();
+        assert!(eq_f64(to_lat_lon(p).1.rem_euclid(PI), 0.3));
+        assert!(eq_f64(p * (u ^ v), 0.0));
+        assert_eq!(p, -q);
+        let c = (b - a) * 0.5 + a;
+        let (p, _) = great_circle_inter(a, b, np, c).unwrap();
+        assert!(eq_f64(p.z, 0.0) && eq_f64(p.dis(), 1.0));
+        assert!(great_circle_inter(a, b, b, a).is_none());
+    }
+}
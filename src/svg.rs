@@ -0,0 +1,291 @@
+use crate::point::*;
+use crate::line::*;
+use crate::round::*;
+use crate::convex_hull::*;
+use crate::aabb::*;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// 可以加入 `Scene` 中绘制的图形。
+#[derive(Debug, Clone)]
+pub enum Shape {
+    /// 点，绘制为实心小圆点。
+    Point(Point),
+    /// 线段。
+    Line(Line),
+    /// 圆。
+    Round(Round),
+    /// 凸包，按逆时针顺序存储顶点，绘制为半透明填充的多边形。
+    Polygon(Vec<Point>),
+}
+
+impl From<Point> for Shape {
+    fn from(p: Point) -> Self {
+        Shape::Point(p)
+    }
+}
+
+impl From<Line> for Shape {
+    fn from(l: Line) -> Self {
+        Shape::Line(l)
+    }
+}
+
+impl From<Round> for Shape {
+    fn from(rd: Round) -> Self {
+        Shape::Round(rd)
+    }
+}
+
+impl From<&ConvexHull> for Shape {
+    fn from(hull: &ConvexHull) -> Self {
+        Shape::Polygon(hull.get_points())
+    }
+}
+
+impl Shape {
+    /// 图形的包围盒。
+    fn bounds(&self) -> Option<Aabb> {
+        match self {
+            Shape::Point(p) => Some(Aabb::new(*p, *p)),
+            Shape::Line(l) => Some(Aabb::from_line(*l)),
+            Shape::Round(rd) => Some(Aabb::from_round(*rd)),
+            Shape::Polygon(pts) => Aabb::from_points(pts),
+        }
+    }
+
+    /// 放置标签的位置。
+    fn anchor(&self) -> Option<Point> {
+        match self {
+            Shape::Point(p) => Some(*p),
+            Shape::Line(l) => Some((l.a + l.b) / 2.0),
+            Shape::Round(rd) => Some(rd.o),
+            Shape::Polygon(pts) => pts.first().copied(),
+        }
+    }
+}
+
+/// 场景中的一个图形及其颜色与标签。
+#[derive(Debug, Clone)]
+struct Item {
+    shape: Shape,
+    color: String,
+    label: Option<String>,
+}
+
+/// `Scene` 用于调试的 SVG 场景，收集带颜色与标签的图形，输出时自动计算视口使所有图形可见。
+///
+/// 颜色可以是任意 SVG 支持的颜色字符串，例如 `"red"` 或 `"#1f77b4"`。输出的图像中 y 轴朝上，与数学坐标系一致。
+///
+///     use rust_geometry::point::Point;
+///     use rust_geometry::round::Round;
+///     use rust_geometry::convex_hull::ConvexHull;
+///     use rust_geometry::svg::Scene;
+///
+///     let pts = vec![Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(1.0, 3.0)];
+///     let hull = ConvexHull::get_convex_hull(pts.clone());
+///     let mut scene = Scene::new();
+///     scene.add(&hull, "steelblue").add(Round::new(Point::new(2.0, 1.0), 1.0), "red").axes(true).grid(1.0);
+///     for (i, &p) in pts.iter().enumerate() {
+///         scene.add_labeled(p, "black", &format!("P{}", i));
+///     }
+///     let svg = scene.to_svg();
+///     assert!(svg.starts_with("<svg") && svg.contains(">P2</text>"));
+///
+#[derive(Debug, Clone)]
+pub struct Scene {
+    items: Vec<Item>,
+    width: f64,
+    height: f64,
+    axes: bool,
+    grid: Option<f64>,
+}
+
+impl Default for Scene {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 世界坐标到图像坐标的映射，图像坐标的 y 轴朝下。
+struct Viewport {
+    lo: Point,
+    hi: Point,
+    scale: f64,
+    height: f64,
+}
+
+impl Viewport {
+    fn map(&self, p: Point) -> (f64, f64) {
+        ((p.x - self.lo.x) * self.scale, self.height - (p.y - self.lo.y) * self.scale)
+    }
+}
+
+/// 转义颜色、标签等用户给出的字符串中的 XML 特殊字符。
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;").replace('\'', "&apos;")
+}
+
+impl Scene {
+    /// 创建一个 800x600 像素的空场景，默认不绘制坐标轴与网格。
+    pub fn new() -> Self {
+        Scene { items: Vec::new(), width: 800.0, height: 600.0, axes: false, grid: None }
+    }
+
+    /// 设置图像的像素大小。
+    pub fn size(&mut self, width: f64, height: f64) -> &mut Self {
+        self.width = width;
+        self.height = height;
+        self
+    }
+
+    /// 设置是否绘制坐标轴（仅当原点所在的横线或竖线位于视口内时可见）。
+    pub fn axes(&mut self, axes: bool) -> &mut Self {
+        self.axes = axes;
+        self
+    }
+
+    /// 以世界坐标中的间距 `step` 绘制网格，`step` 不为正时不绘制。
+    pub fn grid(&mut self, step: f64) -> &mut Self {
+        self.grid = if step > 0.0 { Some(step) } else { None };
+        self
+    }
+
+    /// 加入一个图形。
+    pub fn add<S: Into<Shape>>(&mut self, shape: S, color: &str) -> &mut Self {
+        self.items.push(Item { shape: shape.into(), color: color.to_string(), label: None });
+        self
+    }
+
+    /// 加入一个带标签的图形。
+    pub fn add_labeled<S: Into<Shape>>(&mut self, shape: S, color: &str, label: &str) -> &mut Self {
+        self.items.push(Item { shape: shape.into(), color: color.to_string(), label: Some(label.to_string()) });
+        self
+    }
+
+    /// 所有图形的包围盒，场景为空时返回 `None`。
+    pub fn bounds(&self) -> Option<Aabb> {
+        self.items.iter().filter_map(|it| it.shape.bounds()).reduce(|a, b| a.merge(b))
+    }
+
+    /// 计算视口：在包围盒四周留出 5% 的边距，并按图像的宽高比扩展使图形不变形。
+    fn viewport(&self) -> Viewport {
+        let b = self.bounds().unwrap_or(Aabb::new(Point::new(-1.0, -1.0), Point::new(1.0, 1.0)));
+        let pad = (b.width().max(b.height()) * 0.05).max(1e-6);
+        let (w, h) = (b.width() + 2.0 * pad, b.height() + 2.0 * pad);
+        let scale = (self.width / w).min(self.height / h);
+        let half = Point::new(self.width / scale, self.height / scale) / 2.0;
+        let c = b.center();
+        Viewport { lo: c - half, hi: c + half, scale, height: self.height }
+    }
+
+    /// 生成 SVG 文本。
+    pub fn to_svg(&self) -> String {
+        let vp = self.viewport();
+        let mut out = String::new();
+        let _ = writeln!(out, r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="0 0 {} {}">"#,
+                         self.width, self.height, self.width, self.height);
+        let _ = writeln!(out, r#"<rect width="100%" height="100%" fill="white"/>"#);
+
+        let seg = |out: &mut String, a: Point, b: Point, color: &str, width: f64| {
+            let ((x1, y1), (x2, y2)) = (vp.map(a), vp.map(b));
+            let _ = writeln!(out, r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="{}" stroke-width="{}"/>"#,
+                             x1, y1, x2, y2, escape(color), width);
+        };
+        if let Some(step) = self.grid {
+            // 网格线过密时不绘制。
+            if (vp.hi.x - vp.lo.x) / step < 500.0 && (vp.hi.y - vp.lo.y) / step < 500.0 {
+                let mut x = (vp.lo.x / step).ceil() * step;
+                while x <= vp.hi.x {
+                    seg(&mut out, Point::new(x, vp.lo.y), Point::new(x, vp.hi.y), "#e0e0e0", 1.0);
+                    x += step;
+                }
+                let mut y = (vp.lo.y / step).ceil() * step;
+                while y <= vp.hi.y {
+                    seg(&mut out, Point::new(vp.lo.x, y), Point::new(vp.hi.x, y), "#e0e0e0", 1.0);
+                    y += step;
+                }
+            }
+        }
+        if self.axes {
+            seg(&mut out, Point::new(vp.lo.x, 0.0), Point::new(vp.hi.x, 0.0), "gray", 1.5);
+            seg(&mut out, Point::new(0.0, vp.lo.y), Point::new(0.0, vp.hi.y), "gray", 1.5);
+        }
+
+        for it in &self.items {
+            let color = escape(&it.color);
+            match &it.shape {
+                Shape::Point(p) => {
+                    let (x, y) = vp.map(*p);
+                    let _ = writeln!(out, r#"<circle cx="{:.2}" cy="{:.2}" r="3" fill="{}"/>"#, x, y, color);
+                }
+                Shape::Line(l) => seg(&mut out, l.a, l.b, &it.color, 2.0),
+                Shape::Round(rd) => {
+                    let (x, y) = vp.map(rd.o);
+                    let _ = writeln!(out, r#"<circle cx="{:.2}" cy="{:.2}" r="{:.2}" fill="none" stroke="{}" stroke-width="2"/>"#,
+                                     x, y, rd.r * vp.scale, color);
+                }
+                Shape::Polygon(pts) => {
+                    let coords: Vec<String> = pts.iter().map(|&p| vp.map(p)).map(|(x, y)| format!("{:.2},{:.2}", x, y)).collect();
+                    let _ = writeln!(out, r#"<polygon points="{}" fill="{}" fill-opacity="0.15" stroke="{}" stroke-width="2"/>"#,
+                                     coords.join(" "), color, color);
+                }
+            }
+            if let (Some(label), Some(p)) = (&it.label, it.shape.anchor()) {
+                let (x, y) = vp.map(p);
+                let _ = writeln!(out, r#"<text x="{:.2}" y="{:.2}" font-size="12" fill="{}">{}</text>"#,
+                                 x + 5.0, y - 5.0, color, escape(label));
+            }
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
+    /// 将 SVG 写入文件。
+    pub fn save<P: AsRef<Path>>(&self, path: P) -> io::Result<()> {
+        fs::write(path, self.to_svg())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn viewport_test() {
+        let mut scene = Scene::new();
+        scene.size(400.0, 400.0)
+            .add(Line::new(Point::new(-2.0, 0.0), Point::new(2.0, 0.0)), "blue")
+            .add_labeled(Round::new(Point::new(0.0, 1.0), 1.0), "red", "a<b");
+        let b = scene.bounds().unwrap();
+        assert_eq!((b.lo, b.hi), (Point::new(-2.0, 0.0), Point::new(2.0, 2.0)));
+
+        // 宽 4.4（含边距）映射到 400 像素，中心 (0, 1) 位于图像中心。
+        let vp = scene.viewport();
+        let (x, y) = vp.map(Point::new(0.0, 1.0));
+        assert!((x - 200.0).abs() < 1e-6 && (y - 200.0).abs() < 1e-6);
+        assert!((vp.scale - 400.0 / 4.4).abs() < 1e-6);
+        let (_, y) = vp.map(Point::new(0.0, 2.0));
+        assert!(y < 200.0);
+
+        let svg = scene.to_svg();
+        assert!(svg.contains("a&lt;b"));
+
+        let mut bad = Scene::new();
+        bad.add_labeled(Point::new(0.0, 0.0), "red\" onload=\"x", "<'&'>")
+            .add(Line::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0)), "a<b");
+        let svg = bad.to_svg();
+        assert!(svg.contains(r#"fill="red&quot; onload=&quot;x""#));
+        assert!(svg.contains(">&lt;&apos;&amp;&apos;&gt;</text>"));
+        assert!(svg.contains(r#"stroke="a&lt;b""#));
+        assert!(!svg.contains("onload=\""));
+        assert_eq!(svg.matches("<line").count(), 1);
+        assert!(svg.ends_with("</svg>\n"));
+
+        scene.axes(true).grid(0.5);
+        assert!(scene.to_svg().matches("<line").count() > 3);
+        assert!(Scene::new().to_svg().starts_with("<svg"));
+    }
+}
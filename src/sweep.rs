@@ -0,0 +1,467 @@
+use crate::*;
+use crate::point::*;
+use crate::line::*;
+use crate::convex_hull::*;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+
+/// 扫描线的状态结构：以线段下标为节点的带父指针的 treap。
+///
+/// 节点之间的顺序由插入时的比较函数决定，删除、前驱、后继均通过节点本身完成而不再比较，
+/// 因此扫描线越过交点后顺序发生变化也不会破坏树的结构。
+struct Status {
+    l: Vec<usize>,
+    r: Vec<usize>,
+    par: Vec<usize>,
+    pri: Vec<u64>,
+    root: usize,
+}
+
+impl Status {
+    fn new(n: usize) -> Self {
+        let mut seed: u64 = 0x2545F4914F6CDD1D;
+        let pri = (0 .. n).map(|_| {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            seed
+        }).collect();
+        Status { l: vec![NIL; n], r: vec![NIL; n], par: vec![NIL; n], pri, root: NIL }
+    }
+
+    fn rotate_up(&mut self, x: usize) {
+        let p = self.par[x];
+        let g = self.par[p];
+        if self.l[p] == x {
+            self.l[p] = self.r[x];
+            if self.r[x] != NIL {
+                self.par[self.r[x]] = p;
+            }
+            self.r[x] = p;
+        }
+        else {
+            self.r[p] = self.l[x];
+            if self.l[x] != NIL {
+                self.par[self.l[x]] = p;
+            }
+            self.l[x] = p;
+        }
+        self.par[p] = x;
+        self.par[x] = g;
+        if g == NIL {
+            self.root = x;
+        }
+        else if self.l[g] == p {
+            self.l[g] = x;
+        }
+        else {
+            self.r[g] = x;
+        }
+    }
+
+    /// 插入节点 `x`，`less(x, y)` 表示 `x` 应排在 `y` 之前。
+    fn insert(&mut self, x: usize, less: impl Fn(usize, usize) -> bool) {
+        if self.root == NIL {
+            self.root = x;
+            return
+        }
+        let mut cur = self.root;
+        loop {
+            let next = if less(x, cur) { &mut self.l[cur] } else { &mut self.r[cur] };
+            if *next == NIL {
+                *next = x;
+                break
+            }
+            cur = *next;
+        }
+        self.par[x] = cur;
+        while self.par[x] != NIL && self.pri[self.par[x]] < self.pri[x] {
+            self.rotate_up(x);
+        }
+    }
+
+    fn remove(&mut self, x: usize) {
+        while self.l[x] != NIL || self.r[x] != NIL {
+            let c = if self.r[x] == NIL || (self.l[x] != NIL && self.pri[self.l[x]] > self.pri[self.r[x]]) {
+                self.l[x]
+            }
+            else {
+                self.r[x]
+            };
+            self.rotate_up(c);
+        }
+        let p = self.par[x];
+        if p == NIL {
+            self.root = NIL;
+        }
+        else if self.l[p] == x {
+            self.l[p] = NIL;
+        }
+        else {
+            self.r[p] = NIL;
+        }
+        self.par[x] = NIL;
+    }
+
+    fn next(&self, mut x: usize) -> usize {
+        if self.r[x] != NIL {
+            x = self.r[x];
+            while self.l[x] != NIL {
+                x = self.l[x];
+            }
+            return x
+        }
+        while self.par[x] != NIL && self.r[self.par[x]] == x {
+            x = self.par[x];
+        }
+        self.par[x]
+    }
+
+    fn prev(&self, mut x: usize) -> usize {
+        if self.l[x] != NIL {
+            x = self.l[x];
+            while self.r[x] != NIL {
+                x = self.r[x];
+            }
+            return x
+        }
+        while self.par[x] != NIL && self.l[self.par[x]] == x {
+            x = self.par[x];
+        }
+        self.par[x]
+    }
+
+    /// 返回第一个不满足 `below` 的节点，不存在时返回 `NIL`。
+    fn lower_bound(&self, below: impl Fn(usize) -> bool) -> usize {
+        let (mut cur, mut ans) = (self.root, NIL);
+        while cur != NIL {
+            if below(cur) {
+                cur = self.r[cur];
+            }
+            else {
+                ans = cur;
+                cur = self.l[cur];
+            }
+        }
+        ans
+    }
+
+    /// 返回最后一个节点，树为空时返回 `NIL`。
+    fn last(&self) -> usize {
+        let mut cur = self.root;
+        while cur != NIL && self.r[cur] != NIL {
+            cur = self.r[cur];
+        }
+        cur
+    }
+}
+
+/// 事件队列的键，按 `ConvexHull::pt_cmp` 的字典序排列。
+#[derive(Debug, Copy, Clone)]
+struct EventKey(Point);
+
+impl PartialEq for EventKey {
+    fn eq(&self, k: &Self) -> bool {
+        self.cmp(k) == Ordering::Equal
+    }
+}
+
+impl Eq for EventKey {}
+
+impl PartialOrd for EventKey {
+    fn partial_cmp(&self, k: &Self) -> Option<Ordering> {
+        Some(self.cmp(k))
+    }
+}
+
+impl Ord for EventKey {
+    fn cmp(&self, k: &Self) -> Ordering {
+        ConvexHull::pt_cmp(&self.0, &k.0)
+    }
+}
+
+/// 将线段端点整理为按字典序从小到大的顺序。
+fn normalize(segs: &[Line]) -> Vec<Line> {
+    segs.iter().map(|&l| {
+        if ConvexHull::pt_cmp(&l.a, &l.b) == Ordering::Greater { Line::new(l.b, l.a) } else { l }
+    }).collect()
+}
+
+/// 线段在扫描点 `p` 所在竖直线上的纵坐标，竖直线段取 `p.y` 截断到线段范围内的值。
+fn y_at(l: &Line, p: Point) -> f64 {
+    if eq_f64(l.a.x, l.b.x) {
+        p.y.max(l.a.y).min(l.b.y)
+    }
+    else {
+        l.a.y + (l.b.y - l.a.y) * (p.x - l.a.x) / (l.b.x - l.a.x)
+    }
+}
+
+/// 扫描点刚越过 `p` 之后，线段 `i` 是否在线段 `j` 的下方。纵坐标相同时按斜率比较，再相同时按下标比较。
+fn below_after(segs: &[Line], i: usize, j: usize, p: Point) -> bool {
+    let (yi, yj) = (y_at(&segs[i], p), y_at(&segs[j], p));
+    if !eq_f64(yi, yj) {
+        return yi < yj
+    }
+    let (di, dj) = (segs[i].vec(), segs[j].vec());
+    let cross = if segs[i].valid() && segs[j].valid() { di.normalize() ^ dj.normalize() } else { 0.0 };
+    if !eq_f64(cross, 0.0) { cross > 0.0 } else { i < j }
+}
+
+/// 使用 Bentley-Ottmann 扫描线算法求出线段集合的所有交点，时间复杂度 O((n + k) log n)，其中 k 为交点个数。
+///
+/// 返回值按交点的字典序排列，每个交点附带经过该点的所有线段下标（从小到大）。端点接触也视为相交；
+/// 共线重叠的线段在重叠部分的端点处报告。
+///
+///     use rust_geometry::point::Point;
+///     use rust_geometry::line::Line;
+///     use rust_geometry::sweep::all_intersections;
+///
+///     let segs = vec![Line::new(Point::new(0.0, 0.0), Point::new(2.0, 2.0)),
+///                     Line::new(Point::new(0.0, 2.0), Point::new(2.0, 0.0)),
+///                     Line::new(Point::new(1.0, 3.0), Point::new(1.0, -3.0)),
+///                     Line::new(Point::new(5.0, 5.0), Point::new(6.0, 5.0))];
+///     let res = all_intersections(&segs);
+///     assert_eq!(res, vec![(Point::new(1.0, 1.0), vec![0, 1, 2])]);
+///
+pub fn all_intersections(segs: &[Line]) -> Vec<(Point, Vec<usize>)> {
+    let segs = normalize(segs);
+    let n = segs.len();
+    let mut queue: BTreeMap<EventKey, Vec<usize>> = BTreeMap::new();
+    for (i, l) in segs.iter().enumerate() {
+        queue.entry(EventKey(l.a)).or_default().push(i);
+        queue.entry(EventKey(l.b)).or_default();
+    }
+
+    let mut status = Status::new(n);
+    let mut res = Vec::new();
+    let schedule = |queue: &mut BTreeMap<EventKey, Vec<usize>>, i: usize, j: usize, p: Point| {
+        if i == NIL || j == NIL {
+            return
+        }
+        if let Some(q) = segs[i].seg_inter(segs[j]) {
+            if ConvexHull::pt_cmp(&q, &p) == Ordering::Greater {
+                queue.entry(EventKey(q)).or_default();
+            }
+        }
+    };
+
+    while let Some((EventKey(p), upper)) = queue.pop_first() {
+        // 找出状态中所有经过 p 的线段，它们在扫描线上连续排列。
+        let mut through = Vec::new();
+        let mut cur = status.lower_bound(|i| y_at(&segs[i], p) < p.y - EPS);
+        while cur != NIL && segs[cur].on_seg(p) {
+            through.push(cur);
+            cur = status.next(cur);
+        }
+
+        let mut ids: Vec<usize> = through.iter().chain(upper.iter()).copied().collect();
+        if ids.len() > 1 {
+            ids.sort();
+            res.push((p, ids));
+        }
+
+        for &i in &through {
+            status.remove(i);
+        }
+        let inserted: Vec<usize> = through.into_iter()
+            .chain(upper)
+            .filter(|&i| segs[i].b != p)
+            .collect();
+        for &i in &inserted {
+            status.insert(i, |x, y| below_after(&segs, x, y, p));
+        }
+
+        if inserted.is_empty() {
+            let above = status.lower_bound(|i| y_at(&segs[i], p) < p.y);
+            let below = if above == NIL { status.last() } else { status.prev(above) };
+            schedule(&mut queue, below, above, p);
+        }
+        else {
+            let lowest = *inserted.iter().min_by(|&&x, &&y| {
+                if below_after(&segs, x, y, p) { Ordering::Less } else { Ordering::Greater }
+            }).unwrap();
+            let highest = *inserted.iter().max_by(|&&x, &&y| {
+                if below_after(&segs, x, y, p) { Ordering::Less } else { Ordering::Greater }
+            }).unwrap();
+            schedule(&mut queue, status.prev(lowest), lowest, p);
+            schedule(&mut queue, highest, status.next(highest), p);
+        }
+    }
+    res
+}
+
+/// Shamos-Hoey 扫描线：按端点顺序插入和删除线段，只检查扫描线上相邻的线段，`conflict(i, j)` 判断一对线段是否算作相交。
+fn shamos_hoey(segs: &[Line], conflict: impl Fn(usize, usize) -> bool) -> Option<(usize, usize)> {
+    let segs = normalize(segs);
+    let mut events: Vec<(Point, bool, usize)> = Vec::with_capacity(segs.len() * 2);
+    for (i, l) in segs.iter().enumerate() {
+        events.push((l.a, false, i));
+        events.push((l.b, true, i));
+    }
+    // 同一点处先插入再删除，使端点相接的线段也能相邻一次。
+    events.sort_by(|x, y| ConvexHull::pt_cmp(&x.0, &y.0).then(x.1.cmp(&y.1)));
+
+    let mut status = Status::new(segs.len());
+    let check = |i: usize, j: usize| {
+        if i != NIL && j != NIL && conflict(i, j) { Some((i.min(j), i.max(j))) } else { None }
+    };
+    for (p, remove, i) in events {
+        if remove {
+            let (prev, next) = (status.prev(i), status.next(i));
+            status.remove(i);
+            if let Some(w) = check(prev, next) {
+                return Some(w)
+            }
+        }
+        else {
+            status.insert(i, |x, y| below_after(&segs, x, y, p));
+            if let Some(w) = check(status.prev(i), i).or_else(|| check(i, status.next(i))) {
+                return Some(w)
+            }
+        }
+    }
+    None
+}
+
+/// 使用 Shamos-Hoey 扫描线算法判断线段集合中是否存在相交的两条线段，时间复杂度 O(n log n)。
+///
+/// 端点接触与共线重叠均视为相交。存在相交时返回扫描过程中找到的第一对线段的下标（较小者在前），否则返回 `None`。
+///
+///     use rust_geometry::point::Point;
+///     use rust_geometry::line::Line;
+///     use rust_geometry::sweep::any_intersection;
+///
+///     let segs = vec![Line::new(Point::new(0.0, 0.0), Point::new(1.0, 0.0)),
+///                     Line::new(Point::new(0.0, 1.0), Point::new(1.0, 1.0)),
+///                     Line::new(Point::new(0.5, 2.0), Point::new(0.5, 0.5))];
+///     assert_eq!(any_intersection(&segs), Some((1, 2)));
+///     assert_eq!(any_intersection(&segs[.. 2]), None);
+///
+pub fn any_intersection(segs: &[Line]) -> Option<(usize, usize)> {
+    shamos_hoey(segs, |i, j| segs[i].seg_intersect(segs[j]))
+}
+
+/// 判断按顺序给出顶点的多边形是否自交，第 `i` 条边为 `pts[i]` 到 `pts[(i + 1) % n]`。
+///
+/// 相邻两条边只在公共顶点处接触不算自交。多边形自交时返回一对相交的边的下标，简单多边形返回 `None`。
+///
+///     use rust_geometry::point::Point;
+///     use rust_geometry::sweep::polygon_self_intersection;
+///
+///     let square = vec![Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(1.0, 1.0), Point::new(0.0, 1.0)];
+///     assert_eq!(polygon_self_intersection(&square), None);
+///
+///     let bowtie = vec![Point::new(0.0, 0.0), Point::new(1.0, 1.0), Point::new(1.0, 0.0), Point::new(0.0, 1.0)];
+///     assert_eq!(polygon_self_intersection(&bowtie), Some((0, 2)));
+///
+pub fn polygon_self_intersection(pts: &[Point]) -> Option<(usize, usize)> {
+    let n = pts.len();
+    let edges: Vec<Line> = (0 .. n).map(|i| Line::new(pts[i], pts[(i + 1) % n])).collect();
+    shamos_hoey(&edges, |i, j| {
+        let (i, j) = (i.min(j), i.max(j));
+        let shared = if j == i + 1 {
+            Some(pts[j])
+        }
+        else if i == 0 && j == n - 1 {
+            Some(pts[0])
+        }
+        else {
+            None
+        };
+        match shared {
+            Some(v) if n > 2 => edges[i].seg_inter(edges[j]) != Some(v),
+            _ => edges[i].seg_intersect(edges[j]),
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::Lcg;
+
+    fn brute(segs: &[Line]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for i in 0 .. segs.len() {
+            for j in i + 1 .. segs.len() {
+                if segs[i].seg_intersect(segs[j]) {
+                    pairs.push((i, j));
+                }
+            }
+        }
+        pairs
+    }
+
+    fn pairs_of(res: &[(Point, Vec<usize>)]) -> Vec<(usize, usize)> {
+        let mut pairs = Vec::new();
+        for (_, ids) in res {
+            for i in 0 .. ids.len() {
+                for j in i + 1 .. ids.len() {
+                    pairs.push((ids[i], ids[j]));
+                }
+            }
+        }
+        pairs.sort();
+        pairs.dedup();
+        pairs
+    }
+
+    fn pseudo_random_segs(n: usize, seed: u64, modulo: u64) -> Vec<Line> {
+        let mut rng = Lcg::new(seed);
+        let mut next = || rng.below(modulo) as f64;
+        (0 .. n).map(|_| Line::new(Point::new(next(), next()), Point::new(next(), next()))).collect()
+    }
+
+    #[test]
+    fn random_test() {
+        for (seed, modulo) in [(1, 1000), (2, 10), (3, 4)] {
+            let segs = pseudo_random_segs(80, seed, modulo);
+            let res = all_intersections(&segs);
+            assert_eq!(pairs_of(&res), brute(&segs));
+            for (p, ids) in &res {
+                assert!(ids.iter().all(|&i| segs[i].on_seg(*p)));
+            }
+        }
+    }
+
+    #[test]
+    fn shared_endpoint_test() {
+        let o = Point::new(1.0, 1.0);
+        let segs = vec![Line::new(o, Point::new(0.0, 0.0)), Line::new(o, Point::new(2.0, 0.0)),
+                        Line::new(o, Point::new(1.0, 3.0)), Line::new(Point::new(0.0, 1.0), Point::new(2.0, 1.0))];
+        let res = all_intersections(&segs);
+        assert_eq!(res, vec![(o, vec![0, 1, 2, 3])]);
+        assert!(all_intersections(&[]).is_empty());
+    }
+
+    #[test]
+    fn any_intersection_test() {
+        for (seed, modulo) in [(5, 1000), (6, 10)] {
+            for n in 2 .. 30 {
+                let segs = pseudo_random_segs(n, seed + n as u64, modulo);
+                let res = any_intersection(&segs);
+                assert_eq!(res.is_some(), !brute(&segs).is_empty());
+                if let Some((i, j)) = res {
+                    assert!(i < j && segs[i].seg_intersect(segs[j]));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn polygon_test() {
+        let pts = vec![Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(4.0, 4.0),
+                       Point::new(2.0, 1.0), Point::new(0.0, 4.0)];
+        assert_eq!(polygon_self_intersection(&pts), None);
+
+        // 顶点 (2, 0) 落在第 0 条边上。
+        let pts = vec![Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(4.0, 4.0),
+                       Point::new(2.0, 0.0), Point::new(0.0, 4.0)];
+        assert_eq!(polygon_self_intersection(&pts).map(|w| w.0), Some(0));
+
+        // 边沿原路折返，与相邻边共线重叠。
+        let pts = vec![Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(1.0, 0.0), Point::new(1.0, 3.0)];
+        assert_eq!(polygon_self_intersection(&pts), Some((0, 1)));
+    }
+}
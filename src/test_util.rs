@@ -0,0 +1,15 @@
+/// 测试中使用的线性同余伪随机数生成器，保证随机测试的数据可以复现。
+pub struct Lcg(u64);
+
+impl Lcg {
+    /// 以种子 `seed` 初始化生成器。
+    pub fn new(seed: u64) -> Self {
+        Lcg(seed)
+    }
+
+    /// 返回 [0, m) 内的伪随机整数。
+    pub fn below(&mut self, m: u64) -> u64 {
+        self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        (self.0 >> 33) % m
+    }
+}
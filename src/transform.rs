@@ -0,0 +1,288 @@
+use crate::*;
+use crate::point::*;
+use crate::line::*;
+use crate::round::*;
+use crate::convex_hull::*;
+use crate::ellipse::*;
+use crate::triangle::*;
+use std::fmt;
+use std::ops::Mul;
+
+/// `Transform2D` 平面仿射变换，用 3x3 齐次坐标矩阵 `m` 表示，点 `(x, y)` 被看作列向量 `(x, y, 1)`。
+///
+/// 矩阵最后一行恒为 `(0, 0, 1)`，因此变换总是把点映到点、把直线映到直线。
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Transform2D {
+    pub m: [[f64; 3]; 3],
+}
+
+impl fmt::Display for Transform2D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let m = &self.m;
+        write!(f, "[{:.5} {:.5} {:.5}; {:.5} {:.5} {:.5}]", m[0][0], m[0][1], m[0][2], m[1][0], m[1][1], m[1][2])
+    }
+}
+
+impl Mul for Transform2D {
+    type Output = Self;
+
+    /// 实现变换的矩阵乘法，`a * b` 表示先做 `b` 再做 `a`。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::transform::Transform2D;
+    ///
+    ///     let t = Transform2D::translation(Point::new(1.0, 0.0)) * Transform2D::scaling(2.0, 2.0);
+    ///     assert_eq!(t.apply(Point::new(1.0, 1.0)), Point::new(3.0, 2.0));
+    ///
+    fn mul(self, t: Self) -> Self {
+        let mut m = [[0.0; 3]; 3];
+        for (i, row) in m.iter_mut().enumerate() {
+            for (j, x) in row.iter_mut().enumerate() {
+                *x = (0 .. 3).map(|k| self.m[i][k] * t.m[k][j]).sum();
+            }
+        }
+        Self { m }
+    }
+}
+
+impl Transform2D {
+    /// 由线性部分 `[[a, b], [c, d]]` 与平移部分 `(e, f)` 构造变换 `p -> (a x + b y + e, c x + d y + f)`。
+    pub fn new(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Self {
+        Transform2D { m: [[a, b, e], [c, d, f], [0.0, 0.0, 1.0]] }
+    }
+
+    /// 恒等变换。
+    pub fn identity() -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, 0.0, 0.0)
+    }
+
+    /// 平移变换，将每个点平移向量 `v`。
+    pub fn translation(v: Point) -> Self {
+        Self::new(1.0, 0.0, 0.0, 1.0, v.x, v.y)
+    }
+
+    /// 绕原点逆时针旋转 `theta`（弧度制），与 `Point::rot` 一致。
+    pub fn rotation(theta: f64) -> Self {
+        let (s, c) = theta.sin_cos();
+        Self::new(c, -s, s, c, 0.0, 0.0)
+    }
+
+    /// 绕点 `o` 逆时针旋转 `theta`（弧度制）。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::transform::Transform2D;
+    ///
+    ///     let t = Transform2D::rotation_about(Point::new(1.0, 1.0), std::f64::consts::PI / 2.0);
+    ///     assert_eq!(t.apply(Point::new(2.0, 1.0)), Point::new(1.0, 2.0));
+    ///
+    pub fn rotation_about(o: Point, theta: f64) -> Self {
+        Self::translation(o) * Self::rotation(theta) * Self::translation(-o)
+    }
+
+    /// 以原点为中心，沿 x 轴缩放 `sx` 倍、沿 y 轴缩放 `sy` 倍。
+    pub fn scaling(sx: f64, sy: f64) -> Self {
+        Self::new(sx, 0.0, 0.0, sy, 0.0, 0.0)
+    }
+
+    /// 以点 `o` 为中心，沿两坐标轴方向分别缩放 `sx`、`sy` 倍。
+    pub fn scaling_about(o: Point, sx: f64, sy: f64) -> Self {
+        Self::translation(o) * Self::scaling(sx, sy) * Self::translation(-o)
+    }
+
+    /// 错切变换 `(x, y) -> (x + kx y, y + ky x)`。
+    pub fn shearing(kx: f64, ky: f64) -> Self {
+        Self::new(1.0, kx, ky, 1.0, 0.0, 0.0)
+    }
+
+    /// 关于直线 `l` 的轴对称变换，`l` 须满足 `valid`。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::line::Line;
+    ///     use rust_geometry::transform::Transform2D;
+    ///
+    ///     let l = Line::new(Point::new(0.0, 1.0), Point::new(1.0, 2.0));
+    ///     let t = Transform2D::reflection(l);
+    ///     assert_eq!(t.apply(Point::new(1.0, 0.0)), Point::new(-1.0, 2.0));
+    ///
+    pub fn reflection(l: Line) -> Self {
+        let u = l.vec().normalize();
+        let (c, s) = (u.x * u.x - u.y * u.y, 2.0 * u.x * u.y);
+        Self::translation(l.a) * Self::new(c, s, s, -c, 0.0, 0.0) * Self::translation(-l.a)
+    }
+
+    /// 变换的复合，返回先做 `self` 再做 `t` 的变换，即 `t * self`。
+    pub fn then(&self, t: Transform2D) -> Self {
+        t * *self
+    }
+
+    /// 线性部分的行列式，为负时变换改变图形的定向。
+    pub fn det(&self) -> f64 {
+        self.m[0][0] * self.m[1][1] - self.m[0][1] * self.m[1][0]
+    }
+
+    /// 逆变换，若变换退化（行列式为 0）返回 `None`。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::transform::Transform2D;
+    ///
+    ///     let t = Transform2D::shearing(1.0, 0.5) * Transform2D::translation(Point::new(2.0, -1.0));
+    ///     let p = Point::new(3.0, 4.0);
+    ///     assert_eq!(t.inverse().unwrap().apply(t.apply(p)), p);
+    ///     assert!(Transform2D::scaling(1.0, 0.0).inverse().is_none());
+    ///
+    pub fn inverse(&self) -> Option<Self> {
+        let d = self.det();
+        if eq_f64(d, 0.0) {
+            return None
+        }
+        let m = &self.m;
+        let (a, b, c, e) = (m[1][1] / d, -m[0][1] / d, -m[1][0] / d, m[0][0] / d);
+        Some(Self::new(a, b, c, e, -(a * m[0][2] + b * m[1][2]), -(c * m[0][2] + e * m[1][2])))
+    }
+
+    /// 判断变换是否为相似变换（保持角度，各方向缩放比例相同），允许包含轴对称。
+    pub fn is_similarity(&self) -> bool {
+        let (x, y) = (self.apply_vec(Point::new(1.0, 0.0)), self.apply_vec(Point::new(0.0, 1.0)));
+        eq_f64(x * y, 0.0) && eq_f64(x.sqrdis(), y.sqrdis())
+    }
+
+    /// 对点做变换。
+    pub fn apply(&self, p: Point) -> Point {
+        self.apply_vec(p) + Point::new(self.m[0][2], self.m[1][2])
+    }
+
+    /// 对向量做变换，只作用线性部分而忽略平移。
+    pub fn apply_vec(&self, v: Point) -> Point {
+        Point::new(self.m[0][0] * v.x + self.m[0][1] * v.y, self.m[1][0] * v.x + self.m[1][1] * v.y)
+    }
+}
+
+/// 可以被 `Transform2D` 作用的图形。
+///
+/// 变换后的图形类型由 `Output` 给出，某些图形只在部分变换下保持类型不变（例如圆只在相似变换下仍是圆），此时 `Output` 为 `Option`。
+pub trait Transformable {
+    type Output;
+
+    /// 返回图形在变换 `t` 下的像。
+    fn transform(&self, t: &Transform2D) -> Self::Output;
+}
+
+impl Transformable for Point {
+    type Output = Point;
+
+    fn transform(&self, t: &Transform2D) -> Point {
+        t.apply(*self)
+    }
+}
+
+impl Transformable for Line {
+    type Output = Line;
+
+    fn transform(&self, t: &Transform2D) -> Line {
+        Line::new(t.apply(self.a), t.apply(self.b))
+    }
+}
+
+impl Transformable for Round {
+    type Output = Option<Round>;
+
+    /// 圆在相似变换下的像仍为圆，半径按 `sqrt(|det|)` 缩放；其余变换返回 `None`，可改用 `Ellipse` 表示。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::round::Round;
+    ///     use rust_geometry::transform::*;
+    ///
+    ///     let rd = Round::new(Point::new(1.0, 0.0), 1.0);
+    ///     let img = rd.transform(&Transform2D::rotation(std::f64::consts::PI / 2.0).then(Transform2D::scaling(3.0, 3.0)));
+    ///     assert_eq!(img.unwrap().o, Point::new(0.0, 3.0));
+    ///     assert!(rd.transform(&Transform2D::scaling(1.0, 2.0)).is_none());
+    ///
+    fn transform(&self, t: &Transform2D) -> Option<Round> {
+        if !t.is_similarity() {
+            return None
+        }
+        Some(Round::new(t.apply(self.o), self.r * t.det().abs().sqrt()))
+    }
+}
+
+impl Transformable for Ellipse {
+    type Output = Ellipse;
+
+    /// 椭圆是单位圆在仿射变换下的像，因此椭圆在任意仿射变换下的像仍为椭圆（变换退化时半轴长可能为 0）。
+    fn transform(&self, t: &Transform2D) -> Ellipse {
+        let u = t.apply_vec(Point::new(self.a, 0.0).rot(self.theta));
+        let v = t.apply_vec(Point::new(0.0, self.b).rot(self.theta));
+        Ellipse::from_round_affine(Round::new(Point::new(0.0, 0.0), 1.0), [[u.x, v.x], [u.y, v.y]], t.apply(self.o))
+    }
+}
+
+impl Transformable for Triangle {
+    type Output = Triangle;
+
+    fn transform(&self, t: &Transform2D) -> Triangle {
+        Triangle::new(t.apply(self.a), t.apply(self.b), t.apply(self.c))
+    }
+}
+
+impl Transformable for ConvexHull {
+    type Output = ConvexHull;
+
+    /// 凸包在仿射变换下的像仍为凸包，但上下凸壳的划分会改变，因此对变换后的顶点重新求凸包。
+    fn transform(&self, t: &Transform2D) -> ConvexHull {
+        ConvexHull::get_convex_hull(self.get_points().into_iter().map(|p| t.apply(p)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn transform_test() {
+        let p = Point::new(2.0, -1.0);
+        assert_eq!(Transform2D::rotation(0.7).apply(p), p.rot(0.7));
+        assert_eq!(Transform2D::identity().apply(p), p);
+
+        let l = Line::new(Point::new(-1.0, 3.0), Point::new(2.0, 1.0));
+        let r = Transform2D::reflection(l);
+        let q = r.apply(p);
+        assert_eq!(l.proj(q), l.proj(p));
+        assert_eq!(r.apply(q), p);
+        assert!(r.is_similarity() && eq_f64(r.det(), -1.0));
+
+        let t = Transform2D::rotation_about(Point::new(1.0, 1.0), 0.3)
+            .then(Transform2D::shearing(0.5, -0.2))
+            .then(Transform2D::scaling_about(Point::new(-2.0, 0.0), 2.0, 3.0));
+        assert!(!t.is_similarity());
+        assert_eq!(t.inverse().unwrap().apply(t.apply(p)), p);
+        assert_eq!(t.then(t.inverse().unwrap()).apply(p), p);
+        assert_eq!(t.apply(p), Transform2D::scaling_about(Point::new(-2.0, 0.0), 2.0, 3.0)
+            .apply(Transform2D::shearing(0.5, -0.2).apply(p.transform(&Transform2D::rotation_about(Point::new(1.0, 1.0), 0.3)))));
+    }
+
+    #[test]
+    fn shape_test() {
+        let t = Transform2D::rotation(PI / 3.0).then(Transform2D::shearing(0.5, 0.0))
+            .then(Transform2D::translation(Point::new(1.0, 2.0)));
+
+        let rd = Round::new(Point::new(1.0, 1.0), 2.0);
+        assert!(rd.transform(&t).is_none());
+        let e = Ellipse::from_round(rd).transform(&t);
+        assert!(eq_f64(e.area(), rd.area() * t.det().abs()));
+        for theta in [0.0_f64, 1.0, 2.5, 4.0] {
+            assert!((e.contains(t.apply(rd.o + Point::new(rd.r, 0.0).rot(theta)))));
+            assert!(!e.contains(t.apply(rd.o + Point::new(rd.r * 1.01, 0.0).rot(theta))));
+        }
+
+        let hull = ConvexHull::get_convex_hull(vec![Point::new(0.0, 0.0), Point::new(3.0, 0.0),
+                                                    Point::new(3.0, 1.0), Point::new(0.0, 2.0)]);
+        let flip = Transform2D::reflection(Line::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0)))
+            .then(Transform2D::scaling(2.0, 1.0));
+        let img = hull.transform(&flip);
+        assert!(img.valid());
+        assert!(eq_f64(img.area(), hull.area() * 2.0));
+        assert_eq!(img.get_points().len(), 4);
+    }
+}
@@ -0,0 +1,276 @@
+use crate::*;
+use crate::point::*;
+use crate::line::*;
+use crate::round::*;
+use std::fmt;
+
+/// `Triangle` 三角形，由三个顶点 `a`、`b`、`c` 表示，顶点顺序可以是顺时针也可以是逆时针。
+///
+/// 三点共线（含重合）时三角形是退化的，此时依赖于三角形非退化的量（角度、内心、外心等）返回 `None`。
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Triangle {
+    pub a: Point,
+    pub b: Point,
+    pub c: Point,
+}
+
+impl fmt::Display for Triangle {
+    /// 支持以三个顶点坐标的形式输出三角形。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::triangle::Triangle;
+    ///
+    ///     let t = Triangle::new(Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(0.0, 1.0));
+    ///     println!("{}", t); // (0.00000,0.00000)-(1.00000,0.00000)-(0.00000,1.00000)
+    ///
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}-{}", self.a, self.b, self.c)
+    }
+}
+
+impl Triangle {
+    /// 通过三个顶点构造三角形。
+    pub fn new(a: Point, b: Point, c: Point) -> Self {
+        Triangle { a, b, c }
+    }
+
+    /// 判断三角形是否退化（三点共线或重合）。
+    pub fn is_degenerate(&self) -> bool {
+        eq_f64(self.signed_area(), 0.0)
+    }
+
+    /// 三角形的有向面积，顶点逆时针时为正。
+    pub fn signed_area(&self) -> f64 {
+        ((self.b - self.a) ^ (self.c - self.a)) / 2.0
+    }
+
+    /// 三角形的面积。
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    /// 三条边的长度，依次为顶点 `a`、`b`、`c` 所对的边。
+    pub fn sides(&self) -> (f64, f64, f64) {
+        ((self.b - self.c).dis(), (self.c - self.a).dis(), (self.a - self.b).dis())
+    }
+
+    /// 三角形的周长。
+    pub fn perimeter(&self) -> f64 {
+        let (la, lb, lc) = self.sides();
+        la + lb + lc
+    }
+
+    /// 三个内角的大小（弧度制），依次为顶点 `a`、`b`、`c` 处的角。
+    ///
+    ///     use rust_geometry::eq_f64;
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::triangle::Triangle;
+    ///
+    ///     let t = Triangle::new(Point::new(0.0, 0.0), Point::new(1.0, 0.0), Point::new(0.0, 1.0));
+    ///     let (x, y, z) = t.angles().unwrap();
+    ///     assert!(eq_f64(x, std::f64::consts::PI / 2.0));
+    ///     assert!(eq_f64(y, z));
+    ///
+    pub fn angles(&self) -> Option<(f64, f64, f64)> {
+        if self.is_degenerate() {
+            return None
+        }
+        let angle = |o: Point, p: Point, q: Point| (p - o).rad(q - o).abs();
+        Some((angle(self.a, self.b, self.c), angle(self.b, self.c, self.a), angle(self.c, self.a, self.b)))
+    }
+
+    /// 点 `p` 关于三角形的重心坐标 `(u, v, w)`，满足 `p = u * a + v * b + w * c` 且 `u + v + w = 1`。
+    pub fn barycentric(&self, p: Point) -> Option<(f64, f64, f64)> {
+        if self.is_degenerate() {
+            return None
+        }
+        let s = self.signed_area() * 2.0;
+        let u = ((self.b - p) ^ (self.c - p)) / s;
+        let v = ((self.c - p) ^ (self.a - p)) / s;
+        Some((u, v, 1.0 - u - v))
+    }
+
+    /// 判断点是否在三角形内（含边界，基于 `EPS`），退化三角形视为线段。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::triangle::Triangle;
+    ///
+    ///     let t = Triangle::new(Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(0.0, 2.0));
+    ///     assert!(t.contains(Point::new(1.0, 1.0)));
+    ///     assert!(!t.contains(Point::new(1.5, 1.5)));
+    ///
+    pub fn contains(&self, p: Point) -> bool {
+        if self.is_degenerate() {
+            return Line::new(self.a, self.b).on_seg(p) || Line::new(self.b, self.c).on_seg(p)
+                || Line::new(self.c, self.a).on_seg(p)
+        }
+        let d1 = (self.b - self.a) ^ (p - self.a);
+        let d2 = (self.c - self.b) ^ (p - self.b);
+        let d3 = (self.a - self.c) ^ (p - self.c);
+        (d1 > -EPS && d2 > -EPS && d3 > -EPS) || (d1 < EPS && d2 < EPS && d3 < EPS)
+    }
+
+    /// 三角形的重心。
+    pub fn centroid(&self) -> Point {
+        (self.a + self.b + self.c) / 3.0
+    }
+
+    /// 三角形的内切圆，圆心为 `incentre` 求出的内心。
+    pub fn incircle(&self) -> Option<Round> {
+        if self.is_degenerate() {
+            return None
+        }
+        Some(Round::new(incentre(self.a, self.b, self.c), self.area() * 2.0 / self.perimeter()))
+    }
+
+    /// 三角形的外接圆，圆心为 `circum` 求出的外心。
+    ///
+    ///     use rust_geometry::eq_f64;
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::triangle::Triangle;
+    ///
+    ///     let t = Triangle::new(Point::new(0.0, 0.0), Point::new(2.0, 0.0), Point::new(0.0, 2.0));
+    ///     let rd = t.circumcircle().unwrap();
+    ///     assert_eq!(rd.o, Point::new(1.0, 1.0));
+    ///     assert!(eq_f64(rd.r, 2.0_f64.sqrt()));
+    ///
+    pub fn circumcircle(&self) -> Option<Round> {
+        if self.is_degenerate() {
+            return None
+        }
+        let o = circum(self.a, self.b, self.c)?;
+        Some(Round::new(o, (o - self.a).dis()))
+    }
+
+    /// 三角形的垂心，由欧拉线关系 `H = A + B + C - 2 O` 求出。
+    pub fn orthocenter(&self) -> Option<Point> {
+        let o = self.circumcircle()?.o;
+        Some(self.a + self.b + self.c - o * 2.0)
+    }
+
+    /// 三角形的九点圆，圆心为外心与垂心的中点，半径为外接圆半径的一半。
+    pub fn nine_point_circle(&self) -> Option<Round> {
+        let rd = self.circumcircle()?;
+        let h = self.a + self.b + self.c - rd.o * 2.0;
+        Some(Round::new((rd.o + h) / 2.0, rd.r / 2.0))
+    }
+
+    /// 三角形的三个旁切圆，依次为顶点 `a`、`b`、`c` 所对的旁切圆。
+    pub fn excircles(&self) -> Option<[Round; 3]> {
+        if self.is_degenerate() {
+            return None
+        }
+        let (la, lb, lc) = self.sides();
+        let (s, area) = ((la + lb + lc) / 2.0, self.area());
+        let (a, b, c) = (self.a, self.b, self.c);
+        Some([
+            Round::new((b * lb + c * lc - a * la) / (lb + lc - la), area / (s - la)),
+            Round::new((a * la + c * lc - b * lb) / (la + lc - lb), area / (s - lb)),
+            Round::new((a * la + b * lb - c * lc) / (la + lb - lc), area / (s - lc)),
+        ])
+    }
+
+    /// 三角形的费马点，即到三个顶点距离之和最小的点。
+    ///
+    /// 若某个内角不小于 120 度，费马点为该顶点；否则在每条边外侧作正三角形，顶点与对应正三角形外顶点的连线交于费马点。
+    ///
+    ///     use rust_geometry::point::Point;
+    ///     use rust_geometry::triangle::Triangle;
+    ///
+    ///     let h = 3.0_f64.sqrt();
+    ///     let t = Triangle::new(Point::new(-1.0, 0.0), Point::new(1.0, 0.0), Point::new(0.0, h));
+    ///     assert_eq!(t.fermat_point(), Some(Point::new(0.0, h / 3.0)));
+    ///
+    pub fn fermat_point(&self) -> Option<Point> {
+        let (x, y, z) = self.angles()?;
+        let limit = 2.0 * std::f64::consts::PI / 3.0 - EPS;
+        if x > limit {
+            return Some(self.a)
+        }
+        if y > limit {
+            return Some(self.b)
+        }
+        if z > limit {
+            return Some(self.c)
+        }
+
+        // 在边 pq 的外侧（与 r 异侧）作正三角形，返回其外顶点。
+        let apex = |p: Point, q: Point, r: Point| {
+            let v = (q - p).rot(std::f64::consts::PI / 3.0);
+            let w = (q - p).rot(-std::f64::consts::PI / 3.0);
+            if ((q - p) ^ v) * ((q - p) ^ (r - p)) < 0.0 { p + v } else { p + w }
+        };
+        let la = Line::new(self.a, apex(self.b, self.c, self.a));
+        let lb = Line::new(self.b, apex(self.c, self.a, self.b));
+        la.inter(lb)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn measure_test() {
+        let t = Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0));
+        assert!(eq_f64(t.area(), 6.0));
+        assert!(eq_f64(t.perimeter(), 12.0));
+        let (x, y, z) = t.angles().unwrap();
+        assert!(eq_f64(x + y + z, std::f64::consts::PI));
+
+        let p = Point::new(1.0, 1.0);
+        let (u, v, w) = t.barycentric(p).unwrap();
+        assert_eq!(t.a * u + t.b * v + t.c * w, p);
+        assert!(t.contains(p));
+        assert!(t.contains(Point::new(2.0, 1.5)));
+        assert!(!t.contains(Point::new(-0.1, 1.0)));
+        assert_eq!(t.centroid(), Point::new(4.0 / 3.0, 1.0));
+    }
+
+    #[test]
+    fn centers_test() {
+        let t = Triangle::new(Point::new(0.0, 0.0), Point::new(4.0, 0.0), Point::new(0.0, 3.0));
+        let ic = t.incircle().unwrap();
+        assert_eq!(ic.o, Point::new(1.0, 1.0));
+        assert!(eq_f64(ic.r, 1.0));
+
+        let cc = t.circumcircle().unwrap();
+        assert_eq!(cc.o, Point::new(2.0, 1.5));
+        assert!(eq_f64(cc.r, 2.5));
+        assert_eq!(t.orthocenter(), Some(Point::new(0.0, 0.0)));
+
+        let np = t.nine_point_circle().unwrap();
+        assert_eq!(np.o, Point::new(1.0, 0.75));
+        assert!(eq_f64((np.o - Point::new(2.0, 0.0)).dis(), np.r));
+
+        for ex in t.excircles().unwrap() {
+            for (p, q) in [(t.a, t.b), (t.b, t.c), (t.c, t.a)] {
+                let l = Line::new(p, q);
+                assert!(eq_f64((l.proj(ex.o) - ex.o).dis(), ex.r));
+            }
+        }
+
+        let f = t.fermat_point().unwrap();
+        let sum = |x: Point| (x - t.a).dis() + (x - t.b).dis() + (x - t.c).dis();
+        for d in [Point::new(1e-3, 0.0), Point::new(0.0, 1e-3), Point::new(-1e-3, 0.0), Point::new(0.0, -1e-3)] {
+            assert!(sum(f) < sum(f + d));
+        }
+
+        let obtuse = Triangle::new(Point::new(0.0, 0.0), Point::new(10.0, 0.0), Point::new(5.0, 1.0));
+        assert_eq!(obtuse.fermat_point(), Some(obtuse.c));
+    }
+
+    #[test]
+    fn degenerate_test() {
+        let t = Triangle::new(Point::new(0.0, 0.0), Point::new(1.0, 1.0), Point::new(2.0, 2.0));
+        assert!(t.is_degenerate());
+        assert!(t.angles().is_none());
+        assert!(t.circumcircle().is_none());
+        assert!(t.incircle().is_none());
+        assert!(t.excircles().is_none());
+        assert!(t.fermat_point().is_none());
+        assert!(t.contains(Point::new(1.5, 1.5)));
+        assert!(!t.contains(Point::new(1.5, 1.0)));
+    }
+}